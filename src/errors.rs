@@ -1,4 +1,5 @@
-use std::fmt::Display;
+use alloc::string::String;
+use core::fmt::Display;
 
 #[derive(Debug)]
 /// Errors on date boundaries
@@ -16,25 +17,55 @@ pub enum DateErrors {
         /// The "month" that provoked the error
         month: u8,
     },
-    /// Enum variant when a formatter field is not resolved
-    FormatDateError,
+    /// Enum variant when a formatter field is not resolved, holding the part of the formatter
+    /// that failed: an unknown letter, a repeated option, an unclosed bracket or the whole
+    /// formatter if a required option is missing
+    FormatDateError(String),
     /// Invalid year variant.
     InvalidYear(u64),
 
     /// Error to return when triying to parse something that cannot be respresented as a number
     InvalidParsing(String),
+
+    /// Error to return when a component of a date string cannot be parsed as a number
+    InvalidComponent {
+        /// The component that failed: "year", "month", "day", "weekday" or "ordinal"
+        component: &'static str,
+        /// The whole string that was being parsed
+        value: String,
+    },
+
+    /// Error to return when converting a date from before year 0, which a
+    /// [Year](crate::Year) cannot hold
+    NegativeYear(i64),
+
+    /// Error to return when a date string does not split into the expected number of parts, like
+    /// "2022-12"
+    WrongPartCount {
+        /// The number of parts the parser needs
+        expected: usize,
+        /// The number of parts the string had
+        found: usize,
+    },
 }
 
 impl Display for DateErrors {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidDay { day } => write!(f, "Invalid Day: provided {}", day),
             Self::InvalidMonth { month } => write!(f, "Invalid Month: provided {}", month),
-            Self::FormatDateError => write!(f, "Format not recognized"),
+            Self::FormatDateError(token) => write!(f, "Format not recognized: '{}'", token),
             Self::InvalidYear(year) => write!(f, "Invalif year provided: {}", year),
             Self::InvalidParsing(s) => write!(f, "Cannot parse {}: not a number...", s),
+            Self::InvalidComponent { component, value } => {
+                write!(f, "failed to parse {} in '{}'", component, value)
+            }
+            Self::NegativeYear(year) => write!(f, "Year {} is before year 0", year),
+            Self::WrongPartCount { expected, found } => {
+                write!(f, "expected {} date parts, found {}", expected, found)
+            }
         }
     }
 }
 
-impl std::error::Error for DateErrors {}
+impl core::error::Error for DateErrors {}
@@ -1,7 +1,10 @@
 #![deny(missing_docs)]
 
-use crate::{DateStr, errors::DateErrors};
-use std::ops::{Add, Sub};
+use crate::{
+    DateRange, DateRangeIter, DateStr, Day, Days, Month, SignedDate, Year, errors::DateErrors,
+};
+use alloc::string::{String, ToString};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 /// Trait for easy DateStr making
 ///
@@ -18,7 +21,7 @@ pub trait Into<DateStr> {
 /// Implementation of ToDateStr for String
 impl Into<DateStr> for String {
     fn to_datestr(&self) -> DateStr {
-        DateStr::from_iso_str(self)
+        DateStr::try_from_iso_str(self).unwrap_or_else(|err| panic!("{}", err))
     }
 
     fn try_to_datestr(&self) -> Result<DateStr, crate::errors::DateErrors> {
@@ -29,7 +32,7 @@ impl Into<DateStr> for String {
 /// Implementation of ToDateStr for &str
 impl Into<DateStr> for str {
     fn to_datestr(&self) -> DateStr {
-        DateStr::from_iso_str(self)
+        DateStr::try_from_iso_str(self).unwrap_or_else(|err| panic!("{}", err))
     }
 
     fn try_to_datestr(&self) -> Result<DateStr, crate::errors::DateErrors> {
@@ -37,24 +40,59 @@ impl Into<DateStr> for str {
     }
 }
 
+/// Parses an ISO-8601 date with [DateStr::try_from_iso_str]
+impl TryFrom<&str> for DateStr {
+    type Error = DateErrors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        DateStr::try_from_iso_str(value)
+    }
+}
+
 impl TryFrom<String> for DateStr {
     type Error = DateErrors;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let split: Vec<String> = value.split('-').map(|s| s.to_string()).collect();
-        let year = match split[0].parse::<u64>() {
-            Ok(y) => crate::Year::new(y),
-            Err(_e) => return Err(DateErrors::InvalidParsing(value)),
-        };
-        let month = match split[1].parse::<u8>() {
-            Ok(y) => crate::Month::new(y)?,
-            Err(_e) => return Err(DateErrors::InvalidParsing(value)),
-        };
-        let day = match split[2].parse::<u8>() {
-            Ok(y) => crate::Day::new(y)?,
-            Err(_e) => return Err(DateErrors::InvalidParsing(value)),
-        };
-        Ok(DateStr { year, month, day })
+        DateStr::try_from(value.as_str())
+    }
+}
+
+/// Builds a date from its year, month and day, the inverse of [DateStr::as_tuple]
+///
+/// Returns the errors of [DateStr::new] if the month or day are out of range.
+///
+/// ```rust
+/// # use dates_str::DateStr;
+/// let date: DateStr = DateStr::try_from((2022, 12, 31)).unwrap();
+/// assert_eq!(date.as_tuple(), (2022, 12, 31));
+/// ```
+impl TryFrom<(u64, u8, u8)> for DateStr {
+    type Error = DateErrors;
+
+    fn try_from((year, month, day): (u64, u8, u8)) -> Result<Self, Self::Error> {
+        DateStr::new(Year::new(year), Month::new(month)?, Day::new(day)?)
+    }
+}
+
+/// Converts to a [SignedDate], failing with [DateErrors::InvalidYear] for years above [i64::MAX]
+impl TryFrom<DateStr> for SignedDate {
+    type Error = DateErrors;
+
+    fn try_from(value: DateStr) -> Result<Self, Self::Error> {
+        let (year, month, day) = value.as_tuple();
+        let signed_year: i64 = i64::try_from(year).map_err(|_e| DateErrors::InvalidYear(year))?;
+        SignedDate::new(signed_year, Month::new(month)?, Day::new(day)?)
+    }
+}
+
+/// Converts from a [SignedDate], failing with [DateErrors::NegativeYear] for years before 0
+impl TryFrom<SignedDate> for DateStr {
+    type Error = DateErrors;
+
+    fn try_from(value: SignedDate) -> Result<Self, Self::Error> {
+        let year: u64 =
+            u64::try_from(value.year()).map_err(|_e| DateErrors::NegativeYear(value.year()))?;
+        DateStr::new(Year::new(year), value.month, value.day)
     }
 }
 
@@ -64,6 +102,27 @@ impl From<DateStr> for String {
     }
 }
 
+/// Compares with the ISO-8601 form of the date, so only the zero-padded "YYYY-MM-DD" string is
+/// equal to it
+///
+/// ```rust
+/// # use dates_str::DateStr;
+/// let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+/// assert!(date == "2022-01-05");
+/// assert!(date != "2022-1-5");
+/// ```
+impl PartialEq<str> for DateStr {
+    fn eq(&self, other: &str) -> bool {
+        other == self.to_string().as_str()
+    }
+}
+
+impl PartialEq<&str> for DateStr {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 impl Add for DateStr {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
@@ -75,6 +134,20 @@ impl Add for DateStr {
     }
 }
 
+/// Subtracts each component, assuming 30 day months
+///
+/// Days and months borrow like [Day] and [Month] subtraction do, so the month is always between 1
+/// and 12 and the day between 1 and 30, and the year saturates at 0. Since there is no month or
+/// day 0, equal components cannot give zeros: a date minus itself borrows a month and a year and
+/// gives "0000-11-30", not "0000-00-00". The result is only meaningful as a rough difference.
+///
+/// To get the number of days between two dates use [DateStr::sub_days] instead.
+///
+/// ```rust
+/// # use dates_str::DateStr;
+/// let date: DateStr = DateStr::try_from_iso_str("2022-03-05").unwrap();
+/// assert_eq!((date - date).to_string(), "0000-11-30");
+/// ```
 impl Sub for DateStr {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -85,3 +158,136 @@ impl Sub for DateStr {
         DateStr { day, month, year }
     }
 }
+
+/// Moves the date forwards by a number of days
+///
+/// # Panics
+/// Panics if the resulting year does not fit in a [Year]. See
+/// [DateStr::checked_add_days].
+impl Add<Days> for DateStr {
+    type Output = Self;
+    fn add(self, rhs: Days) -> Self::Output {
+        self.checked_add_days(rhs)
+            .unwrap_or_else(|| panic!("adding {} days to {} overflows the year", rhs.0, self))
+    }
+}
+
+/// Moves the date backwards by a number of days
+///
+/// # Panics
+/// Panics if the result would be before year 0. See [DateStr::checked_sub_days].
+impl Sub<Days> for DateStr {
+    type Output = Self;
+    fn sub(self, rhs: Days) -> Self::Output {
+        self.checked_sub_days(rhs)
+            .unwrap_or_else(|| panic!("subtracting {} days from {} is before year 0", rhs.0, self))
+    }
+}
+
+impl AddAssign<Days> for DateStr {
+    fn add_assign(&mut self, rhs: Days) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Days> for DateStr {
+    fn sub_assign(&mut self, rhs: Days) {
+        *self = *self - rhs;
+    }
+}
+
+impl IntoIterator for &DateRange {
+    type Item = DateStr;
+    type IntoIter = DateRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DateRangeIter {
+            next: (self.start <= self.end).then_some(self.start),
+            end: self.end,
+        }
+    }
+}
+
+impl Iterator for DateRangeIter {
+    type Item = DateStr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current: DateStr = self.next?;
+        self.next = current
+            .checked_add_days(Days(1))
+            .filter(|next| *next <= self.end);
+        Some(current)
+    }
+}
+
+/// Converts to a [chrono::NaiveDate], failing with [DateErrors::InvalidYear] if the year is out
+/// of the range [chrono::NaiveDate] supports.
+#[cfg(feature = "chrono")]
+impl TryFrom<DateStr> for chrono::NaiveDate {
+    type Error = DateErrors;
+
+    fn try_from(value: DateStr) -> Result<Self, Self::Error> {
+        i32::try_from(value.year.0)
+            .ok()
+            .and_then(|year| {
+                chrono::NaiveDate::from_ymd_opt(year, value.month.0 as u32, value.day.0 as u32)
+            })
+            .ok_or(DateErrors::InvalidYear(value.year.0))
+    }
+}
+
+/// Converts from a [chrono::NaiveDate], failing with [DateErrors::NegativeYear] for dates before
+/// year 0.
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for DateStr {
+    type Error = DateErrors;
+
+    fn try_from(value: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+        let year: u64 = u64::try_from(value.year())
+            .map_err(|_e| DateErrors::NegativeYear(value.year() as i64))?;
+        DateStr::new(
+            crate::Year::new(year),
+            crate::Month::new(value.month() as u8)?,
+            crate::Day::new(value.day() as u8)?,
+        )
+    }
+}
+
+/// Converts to a [time::Date], failing with [DateErrors::InvalidYear] if the year is out of the
+/// range [time::Date] supports, ±9999 unless its `large-dates` feature is enabled.
+#[cfg(feature = "time")]
+impl TryFrom<DateStr> for time::Date {
+    type Error = DateErrors;
+
+    fn try_from(value: DateStr) -> Result<Self, Self::Error> {
+        let year_error = |_e| DateErrors::InvalidYear(value.year.0);
+        let year: i32 = i32::try_from(value.year.0).map_err(year_error)?;
+        let month: time::Month =
+            time::Month::try_from(value.month.0).map_err(|_e| DateErrors::InvalidMonth {
+                month: value.month.0,
+            })?;
+        time::Date::from_calendar_date(year, month, value.day.0).map_err(
+            |err: time::error::ComponentRange| match err.name() {
+                "day" => DateErrors::InvalidDay { day: value.day.0 },
+                _ => DateErrors::InvalidYear(value.year.0),
+            },
+        )
+    }
+}
+
+/// Converts from a [time::Date], failing with [DateErrors::NegativeYear] for dates before year 0.
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for DateStr {
+    type Error = DateErrors;
+
+    fn try_from(value: time::Date) -> Result<Self, Self::Error> {
+        let year: u64 = u64::try_from(value.year())
+            .map_err(|_e| DateErrors::NegativeYear(value.year() as i64))?;
+        DateStr::new(
+            crate::Year::new(year),
+            crate::Month::new(value.month() as u8)?,
+            crate::Day::new(value.day())?,
+        )
+    }
+}
@@ -9,11 +9,23 @@
 //! For full fledged date & time experiences, see:
 //!  - [chrono](https://crates.io/crates/chrono)
 //!  - [time](https://crates.io/crates/time)
+//!
+//! # Features
+//! - `std` (enabled by default): pieces that need the standard library, such as reading the
+//!   current date. Without it the crate is `#![no_std]` and only needs `alloc`, so parsing and
+//!   formatting also work on embedded targets.
+//! - `chrono`: conversions between [DateStr] and `chrono::NaiveDate`.
+//! - `time`: conversions between [DateStr] and `time::Date`.
 
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-use std::fmt::Display;
-use std::vec::Vec;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
 
 /// Tests
 #[cfg(test)]
@@ -25,14 +37,74 @@ pub mod errors;
 /// Traits and implementations module
 pub mod impls;
 
-/// Allowed formatter options
-const FORMATTER_OPTIONS: [&str; 3] = ["YYYY", "MM", "DD"];
+/// Allowed formatter options, with the date field each one stands for
+///
+/// Longer options go before the shorter ones starting with the same letter, so that `MM` is not
+/// read as two `M`.
+const FORMATTER_OPTIONS: [(&str, FormatField); 10] = [
+    ("YYYY", FormatField::Year),
+    ("MMMM", FormatField::MonthName),
+    ("MMM", FormatField::MonthShortName),
+    ("MM", FormatField::MonthPadded),
+    ("M", FormatField::Month),
+    ("DDDD", FormatField::WeekdayName),
+    ("DDD", FormatField::WeekdayShortName),
+    ("DD", FormatField::DayPadded),
+    ("DO", FormatField::DayOrdinal),
+    ("D", FormatField::Day),
+];
+
+/// Date parts every formatter must print
+const REQUIRED_PARTS: [DatePart; 3] = [DatePart::Year, DatePart::Month, DatePart::Day];
 
-// #[allow(dead_code)]
-// const EPOCH_DATE: &str = "1970-01-01";
+/// Julian Day Number of 1970-01-01
+const JULIAN_DAY_EPOCH: i128 = 2440588;
 
 /// Max number for february month
-const MAX_DAY_FEBR: u8 = 29 as u8;
+const MAX_DAY_FEBR: u8 = 29;
+
+/// English month names, January first
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Spanish month names, January first
+const MONTH_NAMES_ES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// English weekday names, Monday first
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
 
 /// The date struct
 ///
@@ -40,7 +112,16 @@ const MAX_DAY_FEBR: u8 = 29 as u8;
 /// written normally, and December is 12.
 ///
 /// Called DateStr because it comes from a String
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Two dates are equal when they are the same calendar day, whatever string they were parsed
+/// from: "2022/12/31" read with [DateStr::parse_auto] equals "2022-12-31".
+///
+/// Dates are ordered chronologically: by year, then month, then day. So they can be used as keys
+/// of a `BTreeMap`, which iterates over them from the earliest to the latest.
+///
+/// A DateStr is [Copy] and takes 16 bytes. For a smaller form, such as a database column, see
+/// [DateStr::to_packed].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DateStr {
     /// An unsigned 64-bit integer to hold the year
     year: Year,
@@ -51,21 +132,301 @@ pub struct DateStr {
 }
 
 impl DateStr {
+    /// The earliest date that can be represented: 0000-01-01
+    ///
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::MIN.to_string(), "0000-01-01");
+    /// ```
+    pub const MIN: DateStr = DateStr {
+        year: Year(0),
+        month: Month(1),
+        day: Day(1),
+    };
+
+    /// The latest date that can be represented: December 31 of year [u64::MAX]
+    pub const MAX: DateStr = DateStr {
+        year: Year(u64::MAX),
+        month: Month(12),
+        day: Day(31),
+    };
+
+    /// The Unix epoch, 1970-01-01, which the day count conversions start from
+    ///
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::EPOCH.to_string(), "1970-01-01");
+    /// ```
+    pub const EPOCH: DateStr = DateStr {
+        year: Year(1970),
+        month: Month(1),
+        day: Day(1),
+    };
+
     /// Creates a new DateStr from the given parts
+    ///
+    /// The day is checked against the length of the given month, taking leap years into account
+    /// for February.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Year, Month, Day};
+    /// let leap_day = DateStr::new(Year::new(2024), Month::new(2).unwrap(), Day::new(29).unwrap());
+    /// assert!(leap_day.is_ok());
+    /// let not_leap_day = DateStr::new(Year::new(2023), Month::new(2).unwrap(), Day::new(29).unwrap());
+    /// assert!(not_leap_day.is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) if the month is
+    /// not between 1 and 12, and [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay)
+    /// if the day does not exist in that month.
     pub fn new(year: Year, month: Month, day: Day) -> Result<Self, errors::DateErrors> {
-        if month.0 != 2 && day.0 > 29 {
+        if !(1..=12).contains(&month.0) {
+            return Err(errors::DateErrors::InvalidMonth { month: month.0 });
+        }
+        if !(1..=month.length(&year)).contains(&day.0) {
             let err = errors::DateErrors::InvalidDay { day: day.0 };
             return Err(err);
         };
         Ok(Self { year, month, day })
     }
+
+    /// Creates a new DateStr from the given parts, checking each of them
+    ///
+    /// An exact alias of [DateStr::new], with no difference in behavior, for call sites that want
+    /// the validation to be explicit in their name.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Year, Month, Day};
+    /// let april = Month::new(4).unwrap();
+    /// assert!(DateStr::checked_new(Year::new(2023), april, Day::new(30).unwrap()).is_ok());
+    /// assert!(DateStr::checked_new(Year::new(2023), april, Day::new(31).unwrap()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [DateStr::new].
+    #[inline]
+    pub fn checked_new(year: Year, month: Month, day: Day) -> Result<Self, errors::DateErrors> {
+        DateStr::new(year, month, day)
+    }
+
+    /// Creates a date from parts that may be out of range, carrying the extra months and days over
+    ///
+    /// Works like JavaScript's `Date`: month 13 is January of the next year, January 32 is
+    /// February 1, and month or day 0 are the last month of the previous year and the last day of
+    /// the previous month.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::normalize(2022, 13, 1).to_string(), "2023-01-01");
+    /// assert_eq!(DateStr::normalize(2023, 1, 32).to_string(), "2023-02-01");
+    /// assert_eq!(DateStr::normalize(2024, 3, 0).to_string(), "2024-02-29");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the resulting year is before year 0 or does not fit in a [Year].
+    pub fn normalize(year: u64, month: u8, day: u8) -> DateStr {
+        let months: i128 = year as i128 * 12 + month as i128 - 1;
+        let first: DateStr = DateStr {
+            year: Year::new(
+                u64::try_from(months.div_euclid(12)).expect("the normalized year is out of range"),
+            ),
+            month: Month::new_unchecked((months.rem_euclid(12) + 1) as u8),
+            day: Day::new_unchecked(1),
+        };
+        DateStr::civil_from_days(first.days_from_civil() + day as i128 - 1)
+            .expect("the normalized year is out of range")
+    }
+
+    /// Checks the given parts of a date, returning every problem found instead of only the first
+    ///
+    /// The day is checked against the length of the month if the month is valid, and against 31
+    /// otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::validate_all(2024, 2, 29).is_ok());
+    /// assert_eq!(DateStr::validate_all(2023, 13, 32).unwrap_err().len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) and a
+    /// [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay), in that order, for each
+    /// part that is out of range.
+    pub fn validate_all(year: u64, month: u8, day: u8) -> Result<(), Vec<errors::DateErrors>> {
+        let mut problems: Vec<errors::DateErrors> = Vec::new();
+        let max_day: u8 = match Month::new(month) {
+            Ok(month) => month.length(&Year::new(year)),
+            Err(error) => {
+                problems.push(error);
+                31
+            }
+        };
+        if !(1..=max_day).contains(&day) {
+            problems.push(errors::DateErrors::InvalidDay { day });
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Returns the year, month and day as plain numbers
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let (year, month, day) = DateStr::try_from_iso_str("2022-12-31").unwrap().as_tuple();
+    /// assert_eq!((year, month, day), (2022, 12, 31));
+    /// ```
+    pub fn as_tuple(&self) -> (u64, u8, u8) {
+        (self.year.0, self.month.0, self.day.0)
+    }
+
+    /// Packs the date in a single `u64`, as `year << 16 | month << 8 | day`
+    ///
+    /// Packed dates compare in the same order as the dates. Only years below 2^48 fit.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.to_packed(), 2022 << 16 | 12 << 8 | 31);
+    /// assert_eq!(DateStr::from_packed(date.to_packed()).unwrap(), date);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the year is 2^48 or above.
+    pub fn to_packed(&self) -> u64 {
+        assert!(
+            self.year.0 < 1 << 48,
+            "year {} does not fit in a packed date",
+            self.year
+        );
+        self.year.0 << 16 | (self.month.0 as u64) << 8 | self.day.0 as u64
+    }
+
+    /// Unpacks a date made with [DateStr::to_packed]
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) or
+    /// [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) if the packed month or day
+    /// are not a valid date.
+    pub fn from_packed(packed: u64) -> Result<DateStr, errors::DateErrors> {
+        DateStr::new(
+            Year::new(packed >> 16),
+            Month::new((packed >> 8) as u8)?,
+            Day::new(packed as u8)?,
+        )
+    }
+
+    /// Returns a copy of this date with the year changed
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.with_year(2023).unwrap(), DateStr::try_from_iso_str("2023-12-31").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) when moving
+    /// February 29 to a non-leap year.
+    pub fn with_year(&self, year: u64) -> Result<DateStr, errors::DateErrors> {
+        DateStr::new(Year::new(year), self.month, self.day)
+    }
+
+    /// Returns the date the given number of years later
+    ///
+    /// February 29 becomes February 28 when the new year is not a leap year. The year saturates
+    /// at [u64::MAX].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    /// assert_eq!(date.add_years(1).to_string(), "2025-02-28");
+    /// assert_eq!(date.add_years(4).to_string(), "2028-02-29");
+    /// ```
+    pub fn add_years(&self, years: u64) -> DateStr {
+        let year: Year = self.year + Year::new(years);
+        DateStr {
+            year,
+            month: self.month,
+            day: Day::new_unchecked(self.day.0.min(self.month.length(&year))),
+        }
+    }
+
+    /// Returns the date the given number of months later, or [None] if the year would not fit in
+    /// a [Year]
+    ///
+    /// The day is clamped to the length of the new month, so January 31 plus one month is the
+    /// last day of February.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2024-01-31").unwrap();
+    /// assert_eq!(date.checked_add_months(1).unwrap().to_string(), "2024-02-29");
+    /// assert_eq!(date.checked_add_months(23).unwrap().to_string(), "2025-12-31");
+    /// ```
+    pub fn checked_add_months(&self, months: u32) -> Option<DateStr> {
+        let total: u64 = self.month.0 as u64 - 1 + months as u64;
+        let year: Year = self.year.checked_add(&Year::new(total / 12)).ok()?;
+        let month: Month = Month::new_unchecked((total % 12 + 1) as u8);
+        Some(DateStr {
+            year,
+            month,
+            day: Day::new_unchecked(self.day.0.min(month.length(&year))),
+        })
+    }
+
+    /// Returns a copy of this date with the month changed
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.with_month(1).unwrap(), DateStr::try_from_iso_str("2022-01-31").unwrap());
+    /// assert!(date.with_month(2).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) if the month is
+    /// out of bounds, and [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) if the
+    /// current day does not exist in the new month.
+    pub fn with_month(&self, month: u8) -> Result<DateStr, errors::DateErrors> {
+        DateStr::new(self.year, Month::new(month)?, self.day)
+    }
+
+    /// Returns a copy of this date with the day changed
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-02-01").unwrap();
+    /// assert_eq!(date.with_day(28).unwrap(), DateStr::try_from_iso_str("2022-02-28").unwrap());
+    /// assert!(date.with_day(29).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) if the day does not
+    /// exist in the current month.
+    pub fn with_day(&self, day: u8) -> Result<DateStr, errors::DateErrors> {
+        DateStr::new(self.year, self.month, Day::new(day)?)
+    }
 }
 
 /// The `Day` struct. Holds a u8 because there's no 255 days.
 ///
 /// On substractions it's value is casted to a i16 to allow for an ample range of negatives,
 /// and then casted to u8 again on construction.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Day(u8);
 
 impl Day {
@@ -78,52 +439,86 @@ impl Day {
         Ok(Self(value))
     }
 
-    #[allow(dead_code)]
     fn new_unchecked(value: u8) -> Self {
         Self(value)
     }
+
+    /// Returns the English ordinal suffix of the day: "st", "nd", "rd" or "th"
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Day;
+    /// assert_eq!(Day::new(21).unwrap().ordinal_suffix(), "st");
+    /// assert_eq!(Day::new(12).unwrap().ordinal_suffix(), "th");
+    /// ```
+    pub fn ordinal_suffix(&self) -> &'static str {
+        match (self.0 % 10, self.0 % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
 }
 
 impl Display for Day {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", &self.0)
     }
 }
 
-impl std::ops::Add for Day {
+/// Adds two days assuming 30 day months, carrying the whole months over
+///
+/// The resulting day is always between 1 and 30, since there is no day 0: a month is only carried
+/// once the sum goes past 30. So a sum of exactly 60 gives 1 month and 30 days, not 2 months and
+/// 0 days.
+///
+/// ```rust
+/// # use dates_str::{Day, Month};
+/// let (day, months) = Day::new(30).unwrap() + Day::new(30).unwrap();
+/// assert_eq!((day, months), (Day::new(30).unwrap(), Month::new(1).unwrap()));
+/// ```
+impl core::ops::Add for Day {
     type Output = (Self, Month);
     fn add(self, rhs: Self) -> Self::Output {
         let mut sum = self.0 + rhs.0;
         let mut mo = 0;
         while sum > 30 {
-            mo = mo + 1;
-            sum = sum - 30;
+            mo += 1;
+            sum -= 30;
         }
         (Self(sum), Month::new_unchecked(mo))
     }
 }
 
-impl std::ops::Sub for Day {
+/// Subtracts two days assuming 30 day months, borrowing whole months
+///
+/// The inverse of the addition: the resulting day is always between 1 and 30, so a month is
+/// borrowed as soon as the difference is 0 or less. The returned [Month] is the number of months
+/// borrowed, so subtracting a day from itself gives day 30 and one month.
+///
+/// ```rust
+/// # use dates_str::{Day, Month};
+/// let (day, months) = Day::new(1).unwrap() - Day::new(5).unwrap();
+/// assert_eq!((day, months), (Day::new(26).unwrap(), Month::new(1).unwrap()));
+/// ```
+impl core::ops::Sub for Day {
     type Output = (Self, Month);
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut sub = self.0 as i16 - rhs.0 as i16;
-        let mut mos = 0;
-
-        if sub > 0 {
-            return (Self(sub as u8), Month::new_unchecked(mos));
-        }
-
-        while sub * -1 > 30 {
-            mos = mos + 1;
-            sub = sub + 30;
+        let mut sub: i16 = self.0 as i16 - rhs.0 as i16;
+        let mut mos: u8 = 0;
+        while sub < 1 {
+            mos += 1;
+            sub += 30;
         }
         (Self(sub as u8), Month::new_unchecked(mos))
     }
 }
 
 /// The `Month` struct. Holds a u8 because there's just 12 months.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Month(u8);
 
 impl Month {
@@ -138,46 +533,144 @@ impl Month {
     fn new_unchecked(value: u8) -> Self {
         Self(value)
     }
+
+    /// Returns the number of days in this month for the given year
+    ///
+    /// February has 29 days on leap years and 28 otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{Month, Year};
+    /// let february: Month = Month::new(2).unwrap();
+    /// assert_eq!(february.length(&Year::new(2024)), 29);
+    /// assert_eq!(february.length(&Year::new(2023)), 28);
+    /// ```
+    pub fn length(&self, year: &Year) -> u8 {
+        match self.0 {
+            2 if year.is_leap() => MAX_DAY_FEBR,
+            2 => MAX_DAY_FEBR - 1,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the English name of the month
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Month;
+    /// assert_eq!(Month::new(12).unwrap().name(), "December");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        MONTH_NAMES[self.0 as usize - 1]
+    }
+
+    /// Returns the three letter abbreviation of the English name of the month
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Month;
+    /// assert_eq!(Month::new(12).unwrap().short_name(), "Dec");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        &self.name()[..3]
+    }
+
+    /// Returns the next month, and `true` if it wrapped from December to January
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Month;
+    /// assert_eq!(Month::new(6).unwrap().succ(), (Month::new(7).unwrap(), false));
+    /// assert_eq!(Month::new(12).unwrap().succ(), (Month::new(1).unwrap(), true));
+    /// ```
+    pub fn succ(&self) -> (Month, bool) {
+        if self.0 == 12 {
+            (Self(1), true)
+        } else {
+            (Self(self.0 + 1), false)
+        }
+    }
+
+    /// Returns a new `Month` from its English name, either full ("December") or abbreviated to
+    /// three letters ("Dec"). The comparison is case-insensitive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Month;
+    /// assert_eq!(Month::from_name("dec").unwrap(), Month::new(12).unwrap());
+    /// assert_eq!(Month::from_name("December").unwrap(), Month::new(12).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) with a month of
+    /// 0 if the name is not recognized.
+    pub fn from_name(name: &str) -> Result<Self, errors::DateErrors> {
+        MONTH_NAMES
+            .iter()
+            .position(|month| {
+                month.eq_ignore_ascii_case(name)
+                    || (name.len() == 3 && month[..3].eq_ignore_ascii_case(name))
+            })
+            .map(|idx| Self(idx as u8 + 1))
+            .ok_or(errors::DateErrors::InvalidMonth { month: 0 })
+    }
 }
 
 impl Display for Month {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", &self.0)
     }
 }
 
-impl std::ops::Add for Month {
+/// Adds two months, carrying the whole years over
+///
+/// Like the [Day] addition, the resulting month is always between 1 and 12 and a year is only
+/// carried once the sum goes past 12: December plus December is December of the next year.
+///
+/// ```rust
+/// # use dates_str::{Month, Year};
+/// let (month, years) = Month::new(12).unwrap() + Month::new(12).unwrap();
+/// assert_eq!((month, years), (Month::new(12).unwrap(), Year::new(1)));
+/// ```
+impl core::ops::Add for Month {
     type Output = (Self, Year);
     fn add(self, rhs: Self) -> Self::Output {
         let mut sum = self.0 + rhs.0;
         let mut y2a: u64 = 0;
         while sum > 12 {
-            y2a = y2a + 1;
-            sum = sum - 12;
+            y2a += 1;
+            sum -= 12;
         }
         (Self(sum), Year::new(y2a))
     }
 }
 
-impl std::ops::Sub for Month {
+/// Subtracts two months, borrowing whole years
+///
+/// Like the [Day] subtraction, the resulting month is always between 1 and 12 and a year is
+/// borrowed as soon as the difference is 0 or less: March minus March is December and one year.
+///
+/// ```rust
+/// # use dates_str::{Month, Year};
+/// let (month, years) = Month::new(3).unwrap() - Month::new(3).unwrap();
+/// assert_eq!((month, years), (Month::new(12).unwrap(), Year::new(1)));
+/// ```
+impl core::ops::Sub for Month {
     type Output = (Self, Year);
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut sub = self.0 as i16 - rhs.0 as i16;
-        let mut yrs = 0;
-        if sub > 0 {
-            return (Self(sub as u8), Year::new(yrs));
-        }
-        sub = sub * (-1);
-        while sub > 12 {
-            yrs = yrs + 1;
-            sub = sub - 12;
+        let mut sub: i16 = self.0 as i16 - rhs.0 as i16;
+        let mut yrs: u64 = 0;
+        while sub < 1 {
+            yrs += 1;
+            sub += 12;
         }
         (Self(sub as u8), Year::new(yrs))
     }
 }
 
 /// The year struct. Holds a u64
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Year(u64);
 
 impl Year {
@@ -185,203 +678,2429 @@ impl Year {
     pub fn new(value: u64) -> Self {
         Self(value)
     }
+
+    /// Returns `true` if the year is a leap year in the Gregorian calendar.
+    ///
+    /// A year is a leap year when it's divisible by 4, except for years divisible by 100 that are
+    /// not divisible by 400.
+    pub fn is_leap(&self) -> bool {
+        (self.0.is_multiple_of(4) && !self.0.is_multiple_of(100)) || self.0.is_multiple_of(400)
+    }
+
+    /// Returns the first leap year after this one, skipping the year itself if it is a leap year.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Year;
+    /// assert_eq!(Year::new(2022).next_leap(), Year::new(2024));
+    /// assert_eq!(Year::new(2024).next_leap(), Year::new(2028));
+    /// assert_eq!(Year::new(2096).next_leap(), Year::new(2104));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if there is no leap year left before `u64::MAX`.
+    pub fn next_leap(&self) -> Year {
+        (1..=8)
+            .filter_map(|offset| self.0.checked_add(offset).map(Self))
+            .find(Year::is_leap)
+            .unwrap_or_else(|| panic!("there is no leap year after {}", self))
+    }
+
+    /// Returns the last leap year before this one, skipping the year itself if it is a leap year.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Year;
+    /// assert_eq!(Year::new(2022).previous_leap(), Year::new(2020));
+    /// assert_eq!(Year::new(2024).previous_leap(), Year::new(2020));
+    /// assert_eq!(Year::new(2104).previous_leap(), Year::new(2096));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics for year 0, which is the first leap year.
+    pub fn previous_leap(&self) -> Year {
+        (1..=8)
+            .filter_map(|offset| self.0.checked_sub(offset).map(Self))
+            .find(Year::is_leap)
+            .unwrap_or_else(|| panic!("there is no leap year before {}", self))
+    }
+
+    /// Adds two years, or returns [DateErrors::InvalidYear](crate::errors::DateErrors::InvalidYear)
+    /// with the left hand year if the result overflows a `u64`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Year;
+    /// assert_eq!(Year::new(2022).checked_add(&Year::new(1)).unwrap(), Year::new(2023));
+    /// assert!(Year::new(u64::MAX).checked_add(&Year::new(1)).is_err());
+    /// ```
+    pub fn checked_add(&self, rhs: &Year) -> Result<Year, errors::DateErrors> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or(errors::DateErrors::InvalidYear(self.0))
+    }
+
+    /// Subtracts two years, or returns
+    /// [DateErrors::InvalidYear](crate::errors::DateErrors::InvalidYear) with the left hand year
+    /// if the result would be before year 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Year;
+    /// assert_eq!(Year::new(2022).checked_sub(&Year::new(1)).unwrap(), Year::new(2021));
+    /// assert!(Year::new(0).checked_sub(&Year::new(1)).is_err());
+    /// ```
+    pub fn checked_sub(&self, rhs: &Year) -> Result<Year, errors::DateErrors> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or(errors::DateErrors::InvalidYear(self.0))
+    }
 }
 
 impl Display for Year {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", &self.0)
     }
 }
 
-impl std::ops::Add for Year {
+/// Adds two years, saturating at `u64::MAX`.
+///
+/// Use [Year::checked_add] to get an error on overflow instead.
+impl core::ops::Add for Year {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+        self.checked_add(&rhs).unwrap_or(Self(u64::MAX))
     }
 }
 
-impl std::ops::Sub for Year {
+/// Subtracts two years, saturating at year 0.
+///
+/// Use [Year::checked_sub] to get an error on underflow instead.
+impl core::ops::Sub for Year {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+        self.checked_sub(&rhs).unwrap_or(Self(0))
     }
 }
 
-/// The format a [DateStr] will be printed
-#[derive(Debug)]
-pub struct DateFormat {
-    /// The format to be used
-    pub formatter: String,
+/// A number of days, used to move a [DateStr] forwards or backwards in time
+///
+/// ```rust
+/// # use dates_str::{DateStr, Days};
+/// let mut date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+/// date += Days(1);
+/// assert_eq!(date.to_string(), "2023-01-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Days(pub u64);
+
+/// Calendar distance between two dates, as returned by [DateStr::diff]
+///
+/// Adding the years and months to the earlier date (clamping the day to the month's length), and
+/// then the days, gives the later date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateDiff {
+    /// Full years
+    pub years: u64,
+    /// Full months left after the years, from 0 to 11
+    pub months: u8,
+    /// Days left after the months
+    pub days: u8,
 }
 
-impl DateFormat {
-    /// Creates a DateFormat from String or a &str
-    ///
-    /// This method will try to create a [DateFormat] from any type that implements the ToString
-    /// type, although is mainly oriented to String and string slices.
-    ///
-    /// # Example:
-    /// ```rust
-    /// # use dates_str::DateFormat;
-    /// let format: DateFormat = DateFormat::from_string("YYYY-MM-DD", None).unwrap();
-    /// assert_eq!(format.formatter, "YYYY-MM-DD");
-    /// ```
-    /// Above code will create a new DateFormat object. If none is passed as separator, it defaults
-    /// to a dash ('-').
-    ///
-    /// # Example returning error:
-    /// ```rust
-    /// # use dates_str::{DateStr, DateFormat, errors::DateErrors};
-    /// let format: Result<DateFormat, DateErrors> = DateFormat::from_string("2020_10_20", Some('/'));
-    /// assert!(format.is_err());
-    /// ```
-    ///
-    /// When the separator is not explicitly specified, it will give an error if it's not a dash.
-    pub fn from_string<T: ToString>(
-        format: T,
-        separator: Option<char>,
-    ) -> Result<DateFormat, errors::DateErrors> {
-        let separator: char = separator.unwrap_or('-');
-        for fmt_opt in FORMATTER_OPTIONS {
-            if !format
-                .to_string()
-                .split(separator)
-                .any(|e| *e.to_uppercase() == *fmt_opt.to_string())
-            {
-                return Err(errors::DateErrors::FormatDateError);
+/// Prints the non-zero parts with their units, like "2 years, 1 month, 23 days"
+///
+/// An empty difference prints "0 days".
+///
+/// ```rust
+/// # use dates_str::DateDiff;
+/// let diff = DateDiff { years: 1, months: 0, days: 2 };
+/// assert_eq!(diff.to_string(), "1 year, 2 days");
+/// ```
+impl Display for DateDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let parts: [(u64, &str); 3] = [
+            (self.years, "year"),
+            (self.months as u64, "month"),
+            (self.days as u64, "day"),
+        ];
+        let mut written: bool = false;
+        for (amount, unit) in parts.iter().filter(|(amount, _)| *amount > 0) {
+            if written {
+                write!(f, ", ")?;
             }
+            let plural: &str = if *amount == 1 { "" } else { "s" };
+            write!(f, "{} {}{}", amount, unit, plural)?;
+            written = true;
         }
-        Ok(DateFormat {
-            formatter: format.to_string().to_uppercase(),
-        })
+        if !written {
+            write!(f, "0 days")?;
+        }
+        Ok(())
     }
 }
 
-impl DateStr {
+/// A date whose year can be negative, for dates before year 0
+///
+/// Years use the astronomical numbering that ISO-8601 follows: year 0 is 1 BCE, year -1 is 2 BCE,
+/// so year -44 is 45 BCE. Like [DateStr], it uses the proleptic Gregorian calendar, which extends
+/// the Gregorian rules to the years before its adoption in 1582, so historical dates written in
+/// the Julian calendar are a few days apart.
+///
+/// Convert from and to a [DateStr] with [TryFrom].
+///
+/// ```rust
+/// # use dates_str::{Day, Month, SignedDate};
+/// let ides = SignedDate::new(-44, Month::new(3).unwrap(), Day::new(15).unwrap()).unwrap();
+/// assert_eq!(ides.to_string(), "-0044-03-15");
+/// assert_eq!(SignedDate::from_julian_day(ides.to_julian_day()), ides);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignedDate {
+    /// A signed 64-bit integer to hold the year
+    year: i64,
+    /// An unsigned 8-bit integer to hold the month
+    month: Month,
+    /// An unsigned 8-bit integer to hold the day
+    day: Day,
+}
+
+impl SignedDate {
+    /// Creates a new SignedDate from the given parts
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) if the month is
+    /// not between 1 and 12, and [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay)
+    /// if the day does not exist in that month.
+    pub fn new(year: i64, month: Month, day: Day) -> Result<Self, errors::DateErrors> {
+        // Leap years repeat every 400 years, so this year is leap if its remainder is
+        let date: DateStr = DateStr::new(Year::new(year.rem_euclid(400) as u64), month, day)?;
+        Ok(Self {
+            year,
+            month: date.month,
+            day: date.day,
+        })
+    }
+
+    /// Returns the year, negative before 1 BCE
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// Returns the Julian Day Number of this date, like [DateStr::to_julian_day]
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{Day, Month, SignedDate};
+    /// let date = SignedDate::new(-4713, Month::new(11).unwrap(), Day::new(24).unwrap()).unwrap();
+    /// assert_eq!(date.to_julian_day(), 0);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the number does not fit in an `i64`, for years beyond about ±25 quadrillion.
+    pub fn to_julian_day(&self) -> i64 {
+        let jdn: i128 =
+            days_from_civil(self.year as i128, self.month.0, self.day.0) + JULIAN_DAY_EPOCH;
+        i64::try_from(jdn)
+            .unwrap_or_else(|_e| panic!("the Julian day of {} does not fit in an i64", self))
+    }
+
+    /// Returns the date of a Julian Day Number, which can be negative
+    pub fn from_julian_day(jdn: i64) -> SignedDate {
+        let (year, month, day): (i128, u8, u8) = civil_from_days(jdn as i128 - JULIAN_DAY_EPOCH);
+        SignedDate {
+            year: year as i64,
+            month: Month::new_unchecked(month),
+            day: Day::new_unchecked(day),
+        }
+    }
+}
+
+/// Prints the date like its [DateStr] counterpart, with a minus sign before negative years
+impl Display for SignedDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign: &str = if self.year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{:04}-{:02}-{:02}",
+            sign,
+            self.year.unsigned_abs(),
+            self.month.0,
+            self.day.0
+        )
+    }
+}
+
+/// An inclusive range of days, from `start` to `end`
+///
+/// Iterating over a `&DateRange` yields every day in it, in order. If `end` is before `start`
+/// the range is empty.
+///
+/// ```rust
+/// # use dates_str::{DateRange, DateStr};
+/// let range = DateRange {
+///     start: DateStr::try_from_iso_str("2022-12-30").unwrap(),
+///     end: DateStr::try_from_iso_str("2023-01-01").unwrap(),
+/// };
+/// let days: Vec<String> = (&range).into_iter().map(|d| d.to_string()).collect();
+/// assert_eq!(days, ["2022-12-30", "2022-12-31", "2023-01-01"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    /// First day of the range
+    pub start: DateStr,
+    /// Last day of the range, included
+    pub end: DateStr,
+}
+
+impl DateRange {
+    /// Returns `true` if `date` is in the range, including both ends
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateRange, DateStr};
+    /// let range = DateRange {
+    ///     start: DateStr::try_from_iso_str("2022-12-01").unwrap(),
+    ///     end: DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    /// };
+    /// assert!(range.contains(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    /// assert!(!range.contains(&DateStr::try_from_iso_str("2023-01-01").unwrap()));
+    /// ```
+    pub fn contains(&self, date: &DateStr) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// Returns `true` if both ranges have at least one day in common
+    ///
+    /// Ranges are inclusive, so sharing their last and first day counts, but a range ending the
+    /// day before the other starts does not.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateRange, DateStr};
+    /// let range = |start, end| DateRange {
+    ///     start: DateStr::try_from_iso_str(start).unwrap(),
+    ///     end: DateStr::try_from_iso_str(end).unwrap(),
+    /// };
+    /// let december = range("2022-12-01", "2022-12-31");
+    /// assert!(december.overlaps(&range("2022-12-31", "2023-01-15")));
+    /// assert!(!december.overlaps(&range("2023-01-01", "2023-01-15")));
+    /// ```
+    pub fn overlaps(&self, other: &DateRange) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the days both ranges have in common, or [None] if they don't overlap
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateRange, DateStr};
+    /// let range = |start, end| DateRange {
+    ///     start: DateStr::try_from_iso_str(start).unwrap(),
+    ///     end: DateStr::try_from_iso_str(end).unwrap(),
+    /// };
+    /// let december = range("2022-12-01", "2022-12-31");
+    /// let holidays = range("2022-12-24", "2023-01-06");
+    /// assert_eq!(december.intersection(&holidays), Some(range("2022-12-24", "2022-12-31")));
+    /// ```
+    pub fn intersection(&self, other: &DateRange) -> Option<DateRange> {
+        let start: DateStr = self.start.max(other.start);
+        let end: DateStr = self.end.min(other.end);
+        (start <= end).then_some(DateRange { start, end })
+    }
+
+    /// Returns the number of days in the range, counting both ends, or 0 if `end` is before
+    /// `start`
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateRange, DateStr};
+    /// let range = DateRange {
+    ///     start: DateStr::try_from_iso_str("2022-12-01").unwrap(),
+    ///     end: DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    /// };
+    /// assert_eq!(range.len_days(), 31);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the count does not fit in a `u64`, for ranges longer than about 50 quadrillion
+    /// years, such as [DateStr::MIN] to [DateStr::MAX].
+    pub fn len_days(&self) -> u64 {
+        let days: i128 = (self.end.days_from_civil() - self.start.days_from_civil() + 1).max(0);
+        u64::try_from(days).unwrap_or_else(|_e| {
+            panic!(
+                "the days from {} to {} do not fit in a u64",
+                self.start, self.end
+            )
+        })
+    }
+}
+
+/// Iterator over the days of a [DateRange]
+#[derive(Debug, Clone)]
+pub struct DateRangeIter {
+    next: Option<DateStr>,
+    end: DateStr,
+}
+
+/// Days of the week, starting on Monday as in ISO-8601
+///
+/// Casting a variant to an integer gives its index, from 0 for Monday to 6 for Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    /// Monday
+    Monday = 0,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+impl Weekday {
+    /// All weekdays, Monday first
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns `true` for Saturday and Sunday
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+
+    /// Returns the weekday at the given index, where 0 is Monday and 6 is Sunday, or [None] if
+    /// the index is greater than 6.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Weekday;
+    /// assert_eq!(Weekday::from_index(0), Some(Weekday::Monday));
+    /// assert_eq!(Weekday::from_index(7), None);
+    /// ```
+    pub fn from_index(index: u8) -> Option<Weekday> {
+        Weekday::ALL.get(index as usize).copied()
+    }
+
+    /// Returns the English name of the weekday
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Weekday;
+    /// assert_eq!(Weekday::Saturday.long_name(), "Saturday");
+    /// ```
+    pub fn long_name(&self) -> &'static str {
+        WEEKDAY_NAMES[*self as usize]
+    }
+
+    /// Returns the three letter abbreviation of the English name of the weekday
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::Weekday;
+    /// assert_eq!(Weekday::Saturday.short_name(), "Sat");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        &self.long_name()[..3]
+    }
+}
+
+/// The day weeks start on, see [DateStr::week_of_month_starting]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+    /// Weeks start on Monday, as in ISO-8601
+    #[default]
+    Monday,
+    /// Weeks start on Sunday, as in the United States
+    Sunday,
+}
+
+impl WeekStart {
+    /// Returns the first day of the week
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{WeekStart, Weekday};
+    /// assert_eq!(WeekStart::Sunday.first_day(), Weekday::Sunday);
+    /// ```
+    pub fn first_day(&self) -> Weekday {
+        match self {
+            WeekStart::Monday => Weekday::Monday,
+            WeekStart::Sunday => Weekday::Sunday,
+        }
+    }
+}
+
+/// Meteorological seasons, three whole months each, see [DateStr::season]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    /// December to February in the northern hemisphere
+    Winter,
+    /// March to May in the northern hemisphere
+    Spring,
+    /// June to August in the northern hemisphere
+    Summer,
+    /// September to November in the northern hemisphere
+    Autumn,
+}
+
+/// Languages month names can be printed in, see [DateStr::format_localized]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English, the language used by [DateStr::format]
+    #[default]
+    English,
+    /// Spanish, with lowercase month names as written in Spanish
+    Spanish,
+}
+
+impl Locale {
+    /// Returns the name of the month in this language
+    fn month_name(&self, month: &Month) -> &'static str {
+        match self {
+            Locale::English => month.name(),
+            Locale::Spanish => MONTH_NAMES_ES[month.0 as usize - 1],
+        }
+    }
+
+    /// Returns the three letter abbreviation of the month in this language
+    fn month_short_name(&self, month: &Month) -> &'static str {
+        &self.month_name(month)[..3]
+    }
+}
+
+/// The format a [DateStr] will be printed
+#[derive(Debug)]
+pub struct DateFormat {
+    /// The format to be used
+    pub formatter: String,
+}
+
+/// A date field a formatter option stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatField {
+    Year,
+    /// Month without padding: 1 to 12
+    Month,
+    /// Month padded to two digits: 01 to 12
+    MonthPadded,
+    /// Full English month name: January to December
+    MonthName,
+    /// Three letter English month name: Jan to Dec
+    MonthShortName,
+    /// Day without padding: 1 to 31
+    Day,
+    /// Day padded to two digits: 01 to 31
+    DayPadded,
+    /// Day with its English ordinal suffix: 1st to 31st
+    DayOrdinal,
+    /// Full English weekday name: Monday to Sunday
+    WeekdayName,
+    /// Three letter English weekday name: Mon to Sun
+    WeekdayShortName,
+}
+
+/// The part of a date a [FormatField] prints
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DatePart {
+    Year,
+    Month,
+    Day,
+    Weekday,
+}
+
+impl FormatField {
+    /// Returns the date part this field prints
+    fn part(&self) -> DatePart {
+        match self {
+            FormatField::Year => DatePart::Year,
+            FormatField::Month
+            | FormatField::MonthPadded
+            | FormatField::MonthName
+            | FormatField::MonthShortName => DatePart::Month,
+            FormatField::Day | FormatField::DayPadded | FormatField::DayOrdinal => DatePart::Day,
+            FormatField::WeekdayName | FormatField::WeekdayShortName => DatePart::Weekday,
+        }
+    }
+
+    /// Returns the formatter option that stands for this field
+    fn option(&self) -> &'static str {
+        FORMATTER_OPTIONS
+            .iter()
+            .find(|(_, field)| field == self)
+            .map(|(option, _)| *option)
+            .unwrap_or_default()
+    }
+}
+
+/// A piece of a formatter: either a date field or literal text
+#[derive(Debug, PartialEq, Eq)]
+enum FormatToken<'a> {
+    Field(FormatField),
+    Literal(&'a str),
+}
+
+impl DateFormat {
+    /// Creates a DateFormat from String or a &str
+    ///
+    /// This method will try to create a [DateFormat] from any type that implements the ToString
+    /// type, although is mainly oriented to String and string slices.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use dates_str::DateFormat;
+    /// let format: DateFormat = DateFormat::from_string("YYYY-MM-DD", None).unwrap();
+    /// assert_eq!(format.formatter, "YYYY-MM-DD");
+    /// ```
+    /// Above code will create a new DateFormat object. The formatter options are found wherever
+    /// they are in the format, so no separator is needed to read it: the second argument is
+    /// ignored, and only kept so existing callers still compile. Pass [None].
+    ///
+    /// Text inside square brackets is emitted verbatim when formatting, so it can contain letters
+    /// that would otherwise be read as a formatter option:
+    /// ```rust
+    /// # use dates_str::{DateStr, DateFormat};
+    /// let format: DateFormat = DateFormat::from_string("[Year] YYYY, MM-DD", None).unwrap();
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.try_format(format).unwrap(), "Year 2022, 12-31");
+    /// ```
+    ///
+    /// # Example returning error:
+    /// ```rust
+    /// # use dates_str::{DateStr, DateFormat, errors::DateErrors};
+    /// let format: Result<DateFormat, DateErrors> = DateFormat::from_string("2020_10_20", Some('/'));
+    /// assert!(format.is_err());
+    /// ```
+    ///
+    /// The available options are:
+    /// - `YYYY`: the year, padded to at least four digits
+    /// - `MM`: the month padded to two digits, `M`: the month without padding
+    /// - `MMMM`: the English month name, `MMM`: its three letter abbreviation
+    /// - `DD`: the day padded to two digits, `D`: the day without padding, `Do`: the day with its
+    ///   English ordinal suffix (1st, 2nd, 3rd, 4th...)
+    /// - `dddd`: the English weekday name, `ddd`: its three letter abbreviation
+    ///
+    /// The options are found wherever they are, so compact formats such as `YYYYMMDD` work too.
+    /// It will give an error if the year, month or day is missing, if any of them (or the weekday)
+    /// appears more than once, if there are letters or digits that are not an option outside
+    /// square brackets, or if a square bracket is never closed.
+    pub fn from_string<T: ToString>(
+        format: T,
+        _separator: Option<char>,
+    ) -> Result<DateFormat, errors::DateErrors> {
+        let mut formatter: String = String::new();
+        let mut escaped: bool = false;
+        for c in format.to_string().chars() {
+            match c {
+                '[' => escaped = true,
+                ']' => escaped = false,
+                _ => (),
+            }
+            if escaped {
+                formatter.push(c);
+            } else {
+                formatter.extend(c.to_uppercase());
+            }
+        }
+        let tokens: Vec<FormatToken> = DateFormat::tokenize(&formatter)?;
+        let fields: Vec<FormatField> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                FormatToken::Field(field) => Some(*field),
+                FormatToken::Literal(_) => None,
+            })
+            .collect();
+        for (idx, field) in fields.iter().enumerate() {
+            if fields[..idx].iter().any(|seen| seen.part() == field.part()) {
+                return Err(errors::DateErrors::FormatDateError(
+                    field.option().to_string(),
+                ));
+            }
+        }
+        for part in REQUIRED_PARTS {
+            if !fields.iter().any(|field| field.part() == part) {
+                return Err(errors::DateErrors::FormatDateError(formatter));
+            }
+        }
+        Ok(DateFormat { formatter })
+    }
+
+    /// Splits a formatter into formatter options and literal text, in a single pass.
+    ///
+    /// Anything between square brackets is literal text. Returns
+    /// [DateErrors::FormatDateError](crate::errors::DateErrors::FormatDateError) if a bracket is
+    /// never closed, or if a letter or digit outside brackets is not part of a formatter option.
+    fn tokenize(formatter: &str) -> Result<Vec<FormatToken<'_>>, errors::DateErrors> {
+        let mut tokens: Vec<FormatToken> = Vec::new();
+        let mut rest: &str = formatter;
+        while !rest.is_empty() {
+            let (token, tail) = DateFormat::next_token(rest)?;
+            tokens.push(token);
+            rest = tail;
+        }
+        Ok(tokens)
+    }
+
+    /// Reads the first token of a non-empty formatter, returning it with the rest of the
+    /// formatter. Fails like [DateFormat::tokenize].
+    fn next_token(rest: &str) -> Result<(FormatToken<'_>, &str), errors::DateErrors> {
+        if let Some(escaped) = rest.strip_prefix('[') {
+            let end: usize = escaped
+                .find(']')
+                .ok_or_else(|| errors::DateErrors::FormatDateError(rest.to_string()))?;
+            return Ok((FormatToken::Literal(&escaped[..end]), &escaped[end + 1..]));
+        }
+        let c: char = rest.chars().next().unwrap_or_default();
+        match FORMATTER_OPTIONS
+            .iter()
+            .find(|(option, _)| rest.starts_with(option))
+        {
+            Some((option, field)) => Ok((FormatToken::Field(*field), &rest[option.len()..])),
+            None if c.is_alphanumeric() => Err(errors::DateErrors::FormatDateError(c.to_string())),
+            None => Ok((
+                FormatToken::Literal(&rest[..c.len_utf8()]),
+                &rest[c.len_utf8()..],
+            )),
+        }
+    }
+}
+
+impl DateStr {
+    /// Parse a string to a DateStr struct
+    ///
+    /// Parses a string (or any type implementing the [ToString] trait) to a DateStr struct.
+    ///
+    /// The given date must be in ISO-8601 format, that is: YYYY-MM-DD.
+    ///
+    /// Deprecated in favor of [crate::DateStr::try_from_iso_str], which returns a Result with
+    /// understandable errors. This function calls it and panics with the error message.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use dates_str::DateStr;
+    /// let date_string: String = String::from("2022-12-31");
+    /// let new_date_from_string: DateStr = DateStr::from_iso_str(date_string);
+    /// let new_date_from_str: DateStr = DateStr::from_iso_str("2022-12-31");
+    /// assert_eq!(new_date_from_str, new_date_from_string);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the month or day are out of bounds, for example on "2023-13-40".
+    #[deprecated(
+        note = "use `DateStr::try_from_iso_str`, which returns an error instead of panicking"
+    )]
+    pub fn from_iso_str<T: ToString>(string: T) -> DateStr {
+        DateStr::try_from_iso_str(string).unwrap_or_else(|err| panic!("{}", err))
+    }
+
     /// Parse a string to a DateStr struct
     ///
-    /// Parses a string (or any type implementing the [ToString] trait) to a DateStr struct.
+    /// Parses a string (or any type implementing the [ToString] trait) to a DateStr struct. This
+    /// function returns a Result enum.
+    ///
+    /// The given date must be in ISO-8601 format, that is: YYYY-MM-DD. Leading zeros are optional
+    /// in the month and day, so "2022-3-5" is the same date as "2022-03-05".
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// # use dates_str::errors;
+    /// let date_string: String = String::from("2022-12-31");
+    /// let date_from_string: Result<DateStr, errors::DateErrors> = DateStr::try_from_iso_str(date_string);
+    /// assert!(date_from_string.is_ok());;
+    /// ```
+    ///
+    /// # Errors
+    /// Since it checks for month first, it will return a DateErrors::InvalidMonth even if the day
+    /// is wrong too, in wich it would return a DateErrors::InvalidDay.
+    ///
+    /// If the string does not have exactly three parts separated by dashes it returns a
+    /// DateErrors::WrongPartCount. If a component is not a number it returns a
+    /// DateErrors::InvalidComponent naming it:
+    /// ```rust
+    /// # use dates_str::{DateStr, errors::DateErrors};
+    /// let error: DateErrors = DateStr::try_from_iso_str("2022-xx-31").unwrap_err();
+    /// assert_eq!(error.to_string(), "failed to parse month in '2022-xx-31'");
+    /// ```
+    ///
+    /// Year 0 is accepted, as in ISO-8601 where it stands for 1 BC. Use
+    /// [DateStr::try_from_iso_str_strict] to reject it.
+    pub fn try_from_iso_str<T: ToString>(string: T) -> Result<DateStr, errors::DateErrors> {
+        let string: String = string.to_string();
+        let mut parts = string.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(errors::DateErrors::WrongPartCount {
+                expected: 3,
+                found: string.split('-').count(),
+            });
+        };
+        let component_error = |component: &'static str| errors::DateErrors::InvalidComponent {
+            component,
+            value: string.clone(),
+        };
+        let year: u64 = year.parse::<u64>().map_err(|_e| component_error("year"))?;
+        let month: Month = Month::new(month.parse::<u8>().map_err(|_e| component_error("month"))?)?;
+        let day: Day = Day::new(day.parse::<u8>().map_err(|_e| component_error("day"))?)?;
+        DateStr::new(Year::new(year), month, day)
+    }
+
+    /// Parse an ISO-8601 date (YYYY-MM-DD) byte by byte, without allocating
+    ///
+    /// Reads the digits and dashes directly instead of splitting the string first, so it suits
+    /// high-throughput input such as logs. Only an error builds a `String`.
+    ///
+    /// Each component is checked as soon as its dash is reached, in the same order as
+    /// [DateStr::try_from_iso_str], so both give the same dates and errors, with one exception:
+    /// components must be only digits, so a leading `+` sign, which the lenient parser allows as
+    /// in "2022-+12-31", gives
+    /// [DateErrors::InvalidComponent](crate::errors::DateErrors::InvalidComponent) here.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::from_iso_fast("2022-12-31").unwrap();
+    /// assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    /// assert!(DateStr::from_iso_fast("2022-12-3x").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [DateStr::try_from_iso_str], plus the error for a `+` sign.
+    pub fn from_iso_fast(s: &str) -> Result<DateStr, errors::DateErrors> {
+        const COMPONENTS: [&str; 3] = ["year", "month", "day"];
+        let found: usize = s.bytes().filter(|byte| *byte == b'-').count() + 1;
+        if found != 3 {
+            return Err(errors::DateErrors::WrongPartCount { expected: 3, found });
+        }
+        let component_error = |idx: usize| errors::DateErrors::InvalidComponent {
+            component: COMPONENTS[idx],
+            value: s.to_string(),
+        };
+        let mut values: [u64; 3] = [0; 3];
+        let mut digits: [usize; 3] = [0; 3];
+        let mut idx: usize = 0;
+        // The end of the string closes the day like a dash, so each component is checked as soon
+        // as it ends, in the same order as the lenient parser
+        for byte in s.bytes().chain(core::iter::once(b'-')) {
+            match byte {
+                b'-' => {
+                    if digits[idx] == 0 {
+                        return Err(component_error(idx));
+                    }
+                    if idx == 1 {
+                        Month::new(u8::try_from(values[1]).map_err(|_e| component_error(1))?)?;
+                    }
+                    idx += 1;
+                }
+                b'0'..=b'9' => {
+                    values[idx] = values[idx]
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add((byte - b'0') as u64))
+                        .ok_or_else(|| component_error(idx))?;
+                    digits[idx] += 1;
+                }
+                _ => return Err(component_error(idx)),
+            }
+        }
+        let day: u8 = u8::try_from(values[2]).map_err(|_e| component_error(2))?;
+        DateStr::new(
+            Year::new(values[0]),
+            Month::new_unchecked(values[1] as u8),
+            Day::new(day)?,
+        )
+    }
+
+    /// Returns `true` if the string is a valid ISO-8601 date (YYYY-MM-DD)
+    ///
+    /// Accepts the same strings as [DateStr::try_from_iso_str], but without building the date or
+    /// an error, so it's cheap for filtering long lists.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::is_valid_iso_string("2024-02-29"));
+    /// assert!(!DateStr::is_valid_iso_string("2023-02-29"));
+    /// assert!(!DateStr::is_valid_iso_string("2023/02/28"));
+    /// ```
+    pub fn is_valid_iso_string(s: &str) -> bool {
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return false;
+        };
+        match (year.parse::<u64>(), month.parse::<u8>(), day.parse::<u8>()) {
+            (Ok(year), Ok(month), Ok(day)) => {
+                (1..=12).contains(&month) && (1..=Month(month).length(&Year(year))).contains(&day)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse an ISO-8601 date at the start of the string, returning the rest of it
+    ///
+    /// The date is read like with [DateStr::try_from_iso_str], with up to two digits for the month
+    /// and the day, and whatever follows is returned untouched.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let (date, rest) = DateStr::parse_prefix("2022-12-31T23:59:59Z").unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// assert_eq!(rest, "T23:59:59Z");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if the
+    /// string does not start with a year, a month and a day separated by dashes, and the month or
+    /// day error if they are out of range.
+    pub fn parse_prefix(s: &str) -> Result<(DateStr, &str), errors::DateErrors> {
+        let parsing_error = || errors::DateErrors::InvalidParsing(s.to_string());
+        let (year, rest) = split_digits(s, usize::MAX);
+        let (month, rest) = split_digits(rest.strip_prefix('-').ok_or_else(parsing_error)?, 2);
+        let (day, rest) = split_digits(rest.strip_prefix('-').ok_or_else(parsing_error)?, 2);
+        let date: DateStr = DateStr::new(
+            Year::new(year.parse::<u64>().map_err(|_e| parsing_error())?),
+            Month::new(month.parse::<u8>().map_err(|_e| parsing_error())?)?,
+            Day::new(day.parse::<u8>().map_err(|_e| parsing_error())?)?,
+        )?;
+        Ok((date, rest))
+    }
+
+    /// Parse an ISO-8601 date from untrusted input, returning [None] if it's not a valid date
+    ///
+    /// Never panics, whatever the input: empty strings, other separators, non-ASCII text, signs or
+    /// numbers too big for their component all give [None].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::parse_lossy("2022-12-31").is_some());
+    /// assert_eq!(DateStr::parse_lossy("2022-12"), None);
+    /// ```
+    pub fn parse_lossy(s: &str) -> Option<DateStr> {
+        DateStr::try_from_iso_str(s).ok()
+    }
+
+    /// Parse the date part of an ISO-8601 timestamp, dropping the time and zone
+    ///
+    /// Everything from a `T` separator, a `Z` or a `+` offset on is ignored, as well as a
+    /// trailing `-hh:mm` offset, and the rest is parsed with [DateStr::try_from_iso_str].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::parse_date_part("2022-12-31T23:59:59Z").unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// assert_eq!(DateStr::parse_date_part("2022-12-31-05:00").unwrap(), date);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the same errors as [DateStr::try_from_iso_str] for the remaining date.
+    pub fn parse_date_part(s: &str) -> Result<DateStr, errors::DateErrors> {
+        let end: usize = s.find(['T', 't', 'Z', 'z', '+']).unwrap_or(s.len());
+        let mut date: &str = &s[..end];
+        if let Some((head, offset)) = date.rsplit_once('-')
+            && offset.contains(':')
+        {
+            date = head;
+        }
+        DateStr::try_from_iso_str(date)
+    }
+
+    /// Parse one ISO-8601 date per line, keeping the dates and the errors apart
+    ///
+    /// Blank lines are skipped and surrounding whitespace is trimmed. The dates and the errors
+    /// keep the order of their lines.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let (dates, errors) = DateStr::parse_many("2022-12-31\n\nnot a date\n2023-01-01\n");
+    /// assert_eq!(dates.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn parse_many(input: &str) -> (Vec<DateStr>, Vec<errors::DateErrors>) {
+        let mut dates: Vec<DateStr> = Vec::new();
+        let mut errors: Vec<errors::DateErrors> = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            match DateStr::try_from_iso_str(line) {
+                Ok(date) => dates.push(date),
+                Err(error) => errors.push(error),
+            }
+        }
+        (dates, errors)
+    }
+
+    /// Reads a date from an environment variable, or returns `default` if it's not set or is not
+    /// a date
+    ///
+    /// The value is trimmed and parsed with [DateStr::parse_auto], so dashes, slashes, dots or
+    /// spaces can separate the year, month and day.
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::from_env_or("DATES_STR_UNSET_VARIABLE", DateStr::EPOCH);
+    /// assert_eq!(date, DateStr::EPOCH);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_env_or(var: &str, default: DateStr) -> DateStr {
+        std::env::var(var)
+            .ok()
+            .and_then(|value| DateStr::parse_auto(value.trim()).ok())
+            .unwrap_or(default)
+    }
+
+    /// Parse an ISO-8601 date like [DateStr::try_from_iso_str], but rejecting year 0
+    ///
+    /// The Gregorian calendar as commonly used goes from 1 BC to 1 AD, with no year 0 in between.
+    /// The lenient parser accepts year 0 since ISO-8601 does, this one does not.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::try_from_iso_str_strict("0001-01-01").is_ok());
+    /// assert!(DateStr::try_from_iso_str_strict("0000-01-01").is_err());
+    /// assert!(DateStr::try_from_iso_str("0000-01-01").is_ok());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidYear](crate::errors::DateErrors::InvalidYear) with 0 for year
+    /// 0, or the errors of [DateStr::try_from_iso_str].
+    pub fn try_from_iso_str_strict<T: ToString>(string: T) -> Result<DateStr, errors::DateErrors> {
+        let date: DateStr = DateStr::try_from_iso_str(string)?;
+        if date.year.0 == 0 {
+            return Err(errors::DateErrors::InvalidYear(0));
+        }
+        Ok(date)
+    }
+
+    /// Parse the date of an RFC 3339 timestamp, like "2022-12-31T23:59:59Z"
+    ///
+    /// Unlike [DateStr::parse_date_part] the whole timestamp is checked: a four digit year and
+    /// two digit month and day, a `T` separator, a time "hh:mm:ss" with optional fractions of
+    /// second, and a `Z` or a "+hh:mm"/"-hh:mm" offset. Only the date is kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::from_rfc3339("2022-12-31T23:59:59.5+02:00").unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// assert!(DateStr::from_rfc3339("2022-12-31 23:59:59Z").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if the
+    /// timestamp is malformed, and the month or day error if the date is out of range.
+    pub fn from_rfc3339(s: &str) -> Result<DateStr, errors::DateErrors> {
+        let parsing_error = || errors::DateErrors::InvalidParsing(s.to_string());
+        let (date, time): (&str, &str) = s.split_once(['T', 't']).ok_or_else(parsing_error)?;
+        let date_shape: bool = date.len() == 10
+            && date.bytes().enumerate().all(|(idx, byte)| match idx {
+                4 | 7 => byte == b'-',
+                _ => byte.is_ascii_digit(),
+            });
+        if !date_shape || !is_rfc3339_time(time) {
+            return Err(parsing_error());
+        }
+        DateStr::try_from_iso_str(date)
+    }
+
+    /// Parse a US style date: MM/DD/YYYY
+    ///
+    /// The month goes first, then the day and then the year, separated by slashes. Leading zeros
+    /// are optional.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::from_us_str("12/31/2022").unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::WrongPartCount](crate::errors::DateErrors::WrongPartCount) if there
+    /// are not three parts,
+    /// [DateErrors::InvalidComponent](crate::errors::DateErrors::InvalidComponent) if one of them
+    /// is not a number, and [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) or
+    /// [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) if they are out of range.
+    pub fn from_us_str(s: &str) -> Result<DateStr, errors::DateErrors> {
+        let parts: Vec<&str> = s.split('/').collect();
+        if parts.len() != 3 {
+            return Err(errors::DateErrors::WrongPartCount {
+                expected: 3,
+                found: parts.len(),
+            });
+        }
+        let component_error = |component: &'static str| errors::DateErrors::InvalidComponent {
+            component,
+            value: s.to_string(),
+        };
+        let month: u8 = parts[0]
+            .parse::<u8>()
+            .map_err(|_e| component_error("month"))?;
+        let day: u8 = parts[1]
+            .parse::<u8>()
+            .map_err(|_e| component_error("day"))?;
+        let year: u64 = parts[2]
+            .parse::<u64>()
+            .map_err(|_e| component_error("year"))?;
+        DateStr::new(Year::new(year), Month::new(month)?, Day::new(day)?)
+    }
+
+    /// Parse a compact date without separators: YYYYMMDD
+    ///
+    /// The inverse of [DateStr::format_compact]. The string must be exactly eight digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::from_compact("20221231").unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// assert!(DateStr::from_compact("2022-12-31").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if the
+    /// string is not eight digits, and the month or day error if they are out of range.
+    pub fn from_compact(s: &str) -> Result<DateStr, errors::DateErrors> {
+        if s.len() != 8 || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(errors::DateErrors::InvalidParsing(s.to_string()));
+        }
+        let parse_error = |_e| errors::DateErrors::InvalidParsing(s.to_string());
+        let year: u64 = s[..4].parse::<u64>().map_err(parse_error)?;
+        let month: u8 = s[4..6].parse::<u8>().map_err(parse_error)?;
+        let day: u8 = s[6..].parse::<u8>().map_err(parse_error)?;
+        DateStr::new(Year::new(year), Month::new(month)?, Day::new(day)?)
+    }
+
+    /// Parse a date written with an English month name
+    ///
+    /// Accepts dates such as "31 December 2022" or "31 Dec 2022", where the month is either the
+    /// full name or its three letter abbreviation (case-insensitive). The day and the year can be
+    /// on either side of the month, so "2022 Dec 31" is valid too: the component with more than
+    /// two digits is taken as the year.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let long = DateStr::from_long_str("31 December 2022").unwrap();
+    /// let short = DateStr::from_long_str("31 dec 2022").unwrap();
+    /// assert_eq!(long, short);
+    /// assert_eq!(long.to_string(), "2022-12-31");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidMonth](crate::errors::DateErrors::InvalidMonth) if the month
+    /// name is unknown, [DateErrors::WrongPartCount](crate::errors::DateErrors::WrongPartCount) if
+    /// the string does not have three parts,
+    /// [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if the day or year
+    /// are not numbers, and
+    /// [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) if the day does not exist
+    /// in that month.
+    pub fn from_long_str(s: &str) -> Result<DateStr, errors::DateErrors> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        if parts.len() != 3 {
+            return Err(errors::DateErrors::WrongPartCount {
+                expected: 3,
+                found: parts.len(),
+            });
+        }
+        let month: Month = Month::from_name(parts[1])?;
+        let (day, year): (&str, &str) = if parts[0].len() > 2 {
+            (parts[2], parts[0])
+        } else {
+            (parts[0], parts[2])
+        };
+        let year: u64 = year
+            .parse::<u64>()
+            .map_err(|_e| errors::DateErrors::InvalidParsing(s.to_string()))?;
+        let day: u8 = day
+            .parse::<u8>()
+            .map_err(|_e| errors::DateErrors::InvalidParsing(s.to_string()))?;
+        DateStr::new(Year::new(year), month, Day::new(day)?)
+    }
+
+    /// Parse a year-month-day date, detecting the separator
+    ///
+    /// Tries a dash, a slash, a dot and a space, in that order, and parses with the first one that
+    /// splits the string in three numbers. Useful when the input comes from different sources.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::parse_auto("2022/12/31").unwrap();
+    /// assert_eq!(date, DateStr::parse_auto("2022.12.31").unwrap());
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if no
+    /// separator gives three numbers, or the month or day error if the numbers are not a valid
+    /// date.
+    pub fn parse_auto(s: &str) -> Result<DateStr, errors::DateErrors> {
+        for separator in ['-', '/', '.', ' '] {
+            let parts: Vec<&str> = s.split(separator).collect();
+            if parts.len() != 3 {
+                continue;
+            }
+            if let (Ok(year), Ok(month), Ok(day)) = (
+                parts[0].parse::<u64>(),
+                parts[1].parse::<u8>(),
+                parts[2].parse::<u8>(),
+            ) {
+                return DateStr::new(Year::new(year), Month::new(month)?, Day::new(day)?);
+            }
+        }
+        Err(errors::DateErrors::InvalidParsing(s.to_string()))
+    }
+
+    /// Parse a date written in one of the common shapes
+    ///
+    /// Tries, in order, an ISO-8601 date ("2022-12-31"), a day-month-year date with the month
+    /// name ("31 dec 2022", see [DateStr::from_long_str]) and a month-day-year date with a comma
+    /// ("Dec 31, 2022" or "December 31, 2022"). Month names are read case-insensitively.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::parse_flexible("Dec 31, 2022").unwrap();
+    /// assert_eq!(date, DateStr::parse_flexible("31 dec 2022").unwrap());
+    /// assert_eq!(date, DateStr::parse_flexible("2022-12-31").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// A string made only of digits and dashes is read as ISO-8601 alone, and gives the errors of
+    /// [DateStr::try_from_iso_str], such as
+    /// [DateErrors::InvalidDay](crate::errors::DateErrors::InvalidDay) for "2022-02-30". Any other
+    /// string gives [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if it
+    /// has none of the other shapes or the date does not exist.
+    pub fn parse_flexible(s: &str) -> Result<DateStr, errors::DateErrors> {
+        if !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_digit() || byte == b'-') {
+            return DateStr::try_from_iso_str(s);
+        }
+        DateStr::from_long_str(s)
+            .or_else(|_e| DateStr::parse_with_formats(s, &["mmmm d, yyyy", "mmm d, yyyy"]))
+            .map_err(|_e| errors::DateErrors::InvalidParsing(s.to_string()))
+    }
+
+    /// Parse a date with the first of the given formatter patterns that matches it
+    ///
+    /// The patterns use the same options as [DateFormat::from_string], and they are tried in
+    /// order. Month and weekday names are read case-insensitively, in English. A year followed
+    /// right away by another option is read as four digits, and `MM` and `DD` need two digits,
+    /// so compact patterns such as `YYYYMMDD` work too. If a weekday is given it must be the
+    /// date's one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let formats = ["yyyy-mm-dd", "dd/mm/yyyy", "mmmm d, yyyy"];
+    /// let date: DateStr = DateStr::parse_with_formats("December 31, 2022", &formats).unwrap();
+    /// assert_eq!(date.to_string(), "2022-12-31");
+    /// assert_eq!(DateStr::parse_with_formats("31/12/2022", &formats).unwrap(), date);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the error of the last pattern if none of them matches, or
+    /// [DateErrors::InvalidParsing](crate::errors::DateErrors::InvalidParsing) if there are no
+    /// patterns.
+    pub fn parse_with_formats(
+        input: &str,
+        formats: &[&str],
+    ) -> Result<DateStr, errors::DateErrors> {
+        let mut last_error: errors::DateErrors =
+            errors::DateErrors::InvalidParsing(input.to_string());
+        for pattern in formats {
+            let parsed: Result<DateStr, errors::DateErrors> =
+                DateFormat::from_string(pattern, None).and_then(|fmt| {
+                    DateStr::parse_tokens(input, &DateFormat::tokenize(&fmt.formatter)?)
+                });
+            match parsed {
+                Ok(date) => return Ok(date),
+                Err(error) => last_error = error,
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Reads a date from `input` following the formatter tokens, which must match all of it
+    fn parse_tokens(input: &str, tokens: &[FormatToken]) -> Result<DateStr, errors::DateErrors> {
+        let component_error = |component: &'static str| errors::DateErrors::InvalidComponent {
+            component,
+            value: input.to_string(),
+        };
+        let parsing_error = || errors::DateErrors::InvalidParsing(input.to_string());
+        let mut rest: &str = input;
+        let mut year: Option<u64> = None;
+        let mut month: Option<u8> = None;
+        let mut day: Option<u8> = None;
+        let mut weekday: Option<Weekday> = None;
+        for (idx, token) in tokens.iter().enumerate() {
+            let field: &FormatField = match token {
+                FormatToken::Literal(text) => {
+                    rest = rest.strip_prefix(*text).ok_or_else(parsing_error)?;
+                    continue;
+                }
+                FormatToken::Field(field) => field,
+            };
+            match field {
+                FormatField::Year => {
+                    let next_is_field: bool =
+                        matches!(tokens.get(idx + 1), Some(FormatToken::Field(_)));
+                    let (digits, tail) =
+                        split_digits(rest, if next_is_field { 4 } else { usize::MAX });
+                    year = Some(
+                        digits
+                            .parse::<u64>()
+                            .map_err(|_e| component_error("year"))?,
+                    );
+                    rest = tail;
+                }
+                FormatField::Month | FormatField::MonthPadded => {
+                    let (digits, tail) = split_digits(rest, 2);
+                    if *field == FormatField::MonthPadded && digits.len() != 2 {
+                        return Err(component_error("month"));
+                    }
+                    month = Some(
+                        digits
+                            .parse::<u8>()
+                            .map_err(|_e| component_error("month"))?,
+                    );
+                    rest = tail;
+                }
+                FormatField::MonthName | FormatField::MonthShortName => {
+                    let (found, tail) = (1..=12)
+                        .map(Month)
+                        .find_map(|candidate| {
+                            let name: &str = if *field == FormatField::MonthName {
+                                candidate.name()
+                            } else {
+                                candidate.short_name()
+                            };
+                            strip_prefix_ignore_case(rest, name).map(|tail| (candidate, tail))
+                        })
+                        .ok_or_else(|| component_error("month"))?;
+                    month = Some(found.0);
+                    rest = tail;
+                }
+                FormatField::Day | FormatField::DayPadded | FormatField::DayOrdinal => {
+                    let (digits, tail) = split_digits(rest, 2);
+                    if *field == FormatField::DayPadded && digits.len() != 2 {
+                        return Err(component_error("day"));
+                    }
+                    let value: u8 = digits.parse::<u8>().map_err(|_e| component_error("day"))?;
+                    rest = tail;
+                    if *field == FormatField::DayOrdinal {
+                        let suffix: &str = Day::new_unchecked(value).ordinal_suffix();
+                        rest = strip_prefix_ignore_case(rest, suffix)
+                            .ok_or_else(|| component_error("day"))?;
+                    }
+                    day = Some(value);
+                }
+                FormatField::WeekdayName | FormatField::WeekdayShortName => {
+                    let (found, tail) = Weekday::ALL
+                        .iter()
+                        .find_map(|candidate| {
+                            let name: &str = if *field == FormatField::WeekdayName {
+                                candidate.long_name()
+                            } else {
+                                candidate.short_name()
+                            };
+                            strip_prefix_ignore_case(rest, name).map(|tail| (*candidate, tail))
+                        })
+                        .ok_or_else(|| component_error("weekday"))?;
+                    weekday = Some(found);
+                    rest = tail;
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(parsing_error());
+        }
+        let date: DateStr = DateStr::new(
+            Year::new(year.ok_or_else(|| component_error("year"))?),
+            Month::new(month.ok_or_else(|| component_error("month"))?)?,
+            Day::new(day.ok_or_else(|| component_error("day"))?)?,
+        )?;
+        if weekday.is_some_and(|weekday| weekday != date.weekday()) {
+            return Err(component_error("weekday"));
+        }
+        Ok(date)
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the given date, negative for earlier dates
+///
+/// Uses the proleptic Gregorian calendar, following Howard Hinnant's `days_from_civil`
+/// algorithm. Computed on an `i128` so any `u64` or `i64` year fits.
+fn days_from_civil(year: i128, month: u8, day: u8) -> i128 {
+    let month: i128 = month as i128;
+    let year: i128 = year - if month <= 2 { 1 } else { 0 };
+    let era: i128 = year.div_euclid(400);
+    let year_of_era: i128 = year - era * 400;
+    let day_of_year: i128 =
+        (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i128 - 1;
+    let day_of_era: i128 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the year, month and day that is the given number of days from 1970-01-01
+///
+/// The inverse of [days_from_civil], following Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i128) -> (i128, u8, u8) {
+    let days: i128 = days + 719468;
+    let era: i128 = days.div_euclid(146097);
+    let day_of_era: i128 = days - era * 146097;
+    let year_of_era: i128 =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year: i128 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month: i128 = (5 * day_of_year + 2) / 153;
+    let day: u8 = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month: u8 = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year: i128 = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Returns `true` for an RFC 3339 time with its offset, such as "23:59:59.5Z" or "23:59:59-05:00"
+fn is_rfc3339_time(time: &str) -> bool {
+    if !time.is_ascii() || time.len() < 9 {
+        return false;
+    }
+    let number = |digits: &str, max: u8| {
+        digits.len() == 2
+            && digits.bytes().all(|byte| byte.is_ascii_digit())
+            && digits.parse::<u8>().is_ok_and(|value| value <= max)
+    };
+    let (clock, zone): (&str, &str) = time.split_at(8);
+    let clock_shape: bool = &clock[2..3] == ":"
+        && &clock[5..6] == ":"
+        && number(&clock[..2], 23)
+        && number(&clock[3..5], 59)
+        && number(&clock[6..], 60);
+    let zone: &str = match zone.strip_prefix('.') {
+        Some(fraction) => {
+            let (digits, zone) = split_digits(fraction, usize::MAX);
+            if digits.is_empty() {
+                return false;
+            }
+            zone
+        }
+        None => zone,
+    };
+    let zone_shape: bool = matches!(zone, "Z" | "z")
+        || (zone.len() == 6
+            && (zone.starts_with('+') || zone.starts_with('-'))
+            && &zone[3..4] == ":"
+            && number(&zone[1..3], 23)
+            && number(&zone[4..], 59));
+    clock_shape && zone_shape
+}
+
+/// Splits the leading ASCII digits of `s`, at most `max` of them, from the rest
+fn split_digits(s: &str, max: usize) -> (&str, &str) {
+    let end: usize = s.bytes().take(max).take_while(u8::is_ascii_digit).count();
+    s.split_at(end)
+}
+
+/// Removes `prefix` from the start of `s`, ignoring ASCII case
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_head| &s[prefix.len()..])
+}
+
+/// Display trait implementation for DateStr
+///
+/// Prints the date in ISO-8601 format (YYYY-MM-DD), with the year padded to at least four digits
+/// so year 99 prints as "0099". The alternate form (`{:#}`) prints a long date with the English
+/// month name instead, like "31 December 2022".
+///
+/// ```rust
+/// # use dates_str::DateStr;
+/// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+/// assert_eq!(format!("{}", date), "2022-12-31");
+/// assert_eq!(format!("{:#}", date), "31 December 2022");
+/// ```
+impl Display for DateStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            match MONTH_NAMES.get((self.month.0 as usize).wrapping_sub(1)) {
+                Some(name) => write!(f, "{} {} {}", self.day, name, self.year),
+                // Not reachable through the public API, but never index out of the names
+                None => write!(f, "{} {} {}", self.day, self.month, self.year),
+            }
+        } else {
+            write!(
+                f,
+                "{:04}-{:02}-{:02}",
+                self.year.0, self.month.0, self.day.0
+            )
+        }
+    }
+}
+
+impl DateStr {
+    /// Returns the date as an ISO-8601 string (YYYY-MM-DD)
+    ///
+    /// Month and day are always zero-padded to two digits, and the year to four. This is the same
+    /// output as the [Display] implementation, but makes the intent explicit at call sites.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-1-5").unwrap();
+    /// assert_eq!(date.to_iso_string(), "2022-01-05");
+    /// ```
+    pub fn to_iso_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the date as an RFC 3339 timestamp at midnight UTC
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.to_rfc3339(), "2022-12-31T00:00:00Z");
+    /// ```
+    pub fn to_rfc3339(&self) -> String {
+        format!("{}T00:00:00Z", self)
+    }
+
+    /// Returns the date without separators (YYYYMMDD), handy for file names
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    /// assert_eq!(date.format_compact(), "20220105");
+    /// ```
+    pub fn format_compact(&self) -> String {
+        format!("{:04}{:02}{:02}", self.year.0, self.month.0, self.day.0)
+    }
+
+    /// Returns `true` if this date is strictly before `other`
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-30").unwrap();
+    /// assert!(date.is_before(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    /// ```
+    pub fn is_before(&self, other: &DateStr) -> bool {
+        self < other
+    }
+
+    /// Returns `true` if this date is strictly after `other`
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    /// assert!(date.is_after(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    /// ```
+    pub fn is_after(&self, other: &DateStr) -> bool {
+        self > other
+    }
+
+    /// Returns `true` if both dates are the same day
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert!(date.is_same(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    /// ```
+    pub fn is_same(&self, other: &DateStr) -> bool {
+        self == other
+    }
+
+    /// Returns the number of full years elapsed from `other` to this date, like an age
+    ///
+    /// A year only counts once its anniversary has been reached, so from 2000-06-15 to 2022-06-14
+    /// there are 21 years, and 22 on 2022-06-15. If `other` is after this date the result is
+    /// negative. An anniversary on February 29 is reached on March 1 in non-leap years.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let birth: DateStr = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    /// assert_eq!(DateStr::try_from_iso_str("2022-06-14").unwrap().years_since(&birth), 21);
+    /// assert_eq!(DateStr::try_from_iso_str("2022-06-15").unwrap().years_since(&birth), 22);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the number of years does not fit in an `i64`, that is for dates more than
+    /// [i64::MAX] years apart.
+    pub fn years_since(&self, other: &DateStr) -> i64 {
+        i64::try_from(self.full_years_since(other)).unwrap_or_else(|_e| {
+            panic!("the years from {} to {} do not fit in an i64", other, self)
+        })
+    }
+
+    /// Returns the number of full years from `other` to this date, see [DateStr::years_since]
+    fn full_years_since(&self, other: &DateStr) -> i128 {
+        if self < other {
+            return -other.full_years_since(self);
+        }
+        let years: i128 = self.year.0 as i128 - other.year.0 as i128;
+        if (&self.month, &self.day) < (&other.month, &other.day) {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// Returns the age at `reference` of someone born on this date, in completed years
+    ///
+    /// Like [DateStr::years_since] from the birth date, but 0 if `reference` is before it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let birth: DateStr = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    /// assert_eq!(birth.age_on(&DateStr::try_from_iso_str("2022-06-14").unwrap()), 21);
+    /// assert_eq!(birth.age_on(&DateStr::try_from_iso_str("1999-01-01").unwrap()), 0);
+    /// ```
+    pub fn age_on(&self, reference: &DateStr) -> u64 {
+        reference.full_years_since(self).max(0) as u64
+    }
+
+    /// Returns the years, months and days between this date and `other`
+    ///
+    /// The order of the dates does not matter. Days are borrowed from the month before the later
+    /// date, so from 2020-01-15 to 2022-03-10 there are 2 years, 1 month and 23 days, as
+    /// 2022-02-15 is 23 days before 2022-03-10.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateDiff, DateStr};
+    /// let start: DateStr = DateStr::try_from_iso_str("2020-01-15").unwrap();
+    /// let end: DateStr = DateStr::try_from_iso_str("2022-03-10").unwrap();
+    /// assert_eq!(start.diff(&end), DateDiff { years: 2, months: 1, days: 23 });
+    /// assert_eq!(end.diff(&start), start.diff(&end));
+    /// ```
+    pub fn diff(&self, other: &DateStr) -> DateDiff {
+        let (start, end): (&DateStr, &DateStr) = if self <= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut total_months: i128 = (end.year.0 as i128 * 12 + end.month.0 as i128)
+            - (start.year.0 as i128 * 12 + start.month.0 as i128);
+        if end.day < start.day {
+            total_months -= 1;
+        }
+        let anchor_month: i128 = start.month.0 as i128 - 1 + total_months;
+        let year: Year = Year::new((start.year.0 as i128 + anchor_month / 12) as u64);
+        let month: Month = Month::new_unchecked((anchor_month % 12 + 1) as u8);
+        let anchor: DateStr = DateStr {
+            year,
+            month,
+            day: Day::new_unchecked(start.day.0.min(month.length(&year))),
+        };
+        DateDiff {
+            years: (total_months / 12) as u64,
+            months: (total_months % 12) as u8,
+            days: (end.days_from_civil() - anchor.days_from_civil()) as u8,
+        }
+    }
+
+    /// Returns the earliest of the given dates, or [None] if there are none
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let dates = [
+    ///     DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    ///     DateStr::try_from_iso_str("2021-06-15").unwrap(),
+    /// ];
+    /// assert_eq!(DateStr::earliest(&dates), Some(&dates[1]));
+    /// ```
+    pub fn earliest(dates: &[DateStr]) -> Option<&DateStr> {
+        dates.iter().min()
+    }
+
+    /// Returns the latest of the given dates, or [None] if there are none
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let dates = [
+    ///     DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    ///     DateStr::try_from_iso_str("2021-06-15").unwrap(),
+    /// ];
+    /// assert_eq!(DateStr::latest(&dates), Some(&dates[0]));
+    /// ```
+    pub fn latest(dates: &[DateStr]) -> Option<&DateStr> {
+        dates.iter().max()
+    }
+
+    /// Returns this date bounded to the inclusive range between `min` and `max`
+    ///
+    /// Takes `self` by value, like [Ord::clamp], so it's picked over the trait method on a
+    /// [DateStr] value, but it borrows the bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let min: DateStr = DateStr::try_from_iso_str("2022-01-01").unwrap();
+    /// let max: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-06-15").unwrap();
+    /// assert_eq!(date.clamp(&min, &max), max);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `min` is after `max`.
+    pub fn clamp(self, min: &DateStr, max: &DateStr) -> DateStr {
+        assert!(min <= max, "min date {} is after max date {}", min, max);
+        if self < *min {
+            *min
+        } else if self > *max {
+            *max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the number of days from 1970-01-01 to this date, negative for earlier dates
+    fn days_from_civil(&self) -> i128 {
+        days_from_civil(self.year.0 as i128, self.month.0, self.day.0)
+    }
+
+    /// Returns the date that is the given number of days from 1970-01-01, or [None] if its year
+    /// does not fit in a [Year].
+    fn civil_from_days(days: i128) -> Option<DateStr> {
+        let (year, month, day): (i128, u8, u8) = civil_from_days(days);
+        Some(DateStr {
+            year: Year::new(u64::try_from(year).ok()?),
+            month: Month::new_unchecked(month),
+            day: Day::new_unchecked(day),
+        })
+    }
+
+    /// Returns the date the given number of days after this one, or [None] if its year would not
+    /// fit in a [Year].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Days};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.checked_add_days(Days(1)).unwrap().to_string(), "2023-01-01");
+    /// ```
+    pub fn checked_add_days(&self, days: Days) -> Option<DateStr> {
+        DateStr::civil_from_days(self.days_from_civil() + days.0 as i128)
+    }
+
+    /// Returns the date `n` days after this one
+    ///
+    /// Runs in constant time whatever `n` is, since it goes through the number of days since
+    /// 1970-01-01 instead of stepping day by day.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.succ_n(1).to_string(), "2023-01-01");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the resulting year does not fit in a [Year].
+    pub fn succ_n(&self, n: u32) -> DateStr {
+        *self + Days(n as u64)
+    }
+
+    /// Returns the date `n` days before this one
+    ///
+    /// Like [DateStr::succ_n], it runs in constant time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    /// assert_eq!(date.pred_n(1).to_string(), "2022-12-31");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the result would be before year 0.
+    pub fn pred_n(&self, n: u32) -> DateStr {
+        *self - Days(n as u64)
+    }
+
+    /// Returns the date the given number of days before this one, or [None] if it would be before
+    /// year 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Days};
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    /// assert_eq!(date.checked_sub_days(Days(1)).unwrap().to_string(), "2022-12-31");
+    /// let first_day: DateStr = DateStr::try_from_iso_str("0000-01-01").unwrap();
+    /// assert_eq!(first_day.checked_sub_days(Days(1)), None);
+    /// ```
+    pub fn checked_sub_days(&self, days: Days) -> Option<DateStr> {
+        DateStr::civil_from_days(self.days_from_civil() - days.0 as i128)
+    }
+
+    /// Returns the current date in UTC, read from the system clock
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let today: DateStr = DateStr::today();
+    /// assert!(today.is_after(&DateStr::EPOCH));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn today() -> DateStr {
+        let seconds: i128 = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)
+        {
+            Ok(elapsed) => elapsed.as_secs() as i128,
+            Err(error) => -(error.duration().as_secs() as i128),
+        };
+        DateStr::civil_from_days(seconds.div_euclid(86400))
+            .expect("the system clock is set before year 0")
+    }
+
+    /// Returns the day after [DateStr::today]
+    ///
+    /// Only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn tomorrow() -> DateStr {
+        DateStr::today() + Days(1)
+    }
+
+    /// Returns the day before [DateStr::today]
+    ///
+    /// Only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn yesterday() -> DateStr {
+        DateStr::today() - Days(1)
+    }
+
+    /// Returns the Julian Day Number of this date, the number of days since November 24, 4714 BC
+    /// in the proleptic Gregorian calendar
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2000-01-01").unwrap();
+    /// assert_eq!(date.to_julian_day(), 2451545);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the number does not fit in an `i64`, for years beyond about 25 quadrillion, such
+    /// as [DateStr::MAX].
+    pub fn to_julian_day(&self) -> i64 {
+        i64::try_from(self.days_from_civil() + JULIAN_DAY_EPOCH)
+            .unwrap_or_else(|_e| panic!("the Julian day of {} does not fit in an i64", self))
+    }
+
+    /// Returns the date of a Julian Day Number, the inverse of [DateStr::to_julian_day]
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::from_julian_day(2451545).to_string(), "2000-01-01");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the date would be before year 0, that is for a number below 1721060. Use
+    /// [SignedDate::from_julian_day] for earlier dates.
+    pub fn from_julian_day(jdn: i64) -> DateStr {
+        DateStr::civil_from_days(jdn as i128 - JULIAN_DAY_EPOCH)
+            .unwrap_or_else(|| panic!("Julian day {} is before year 0", jdn))
+    }
+
+    /// Returns the day of the week of this date
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.weekday(), Weekday::Saturday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday, the fourth day of the week
+        Weekday::ALL[(self.days_from_civil() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the number of days from this date to `other`, negative if `other` is earlier
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let next_year: DateStr = DateStr::try_from_iso_str("2023-12-31").unwrap();
+    /// assert_eq!(date.days_between(&next_year), 365);
+    /// assert_eq!(next_year.days_between(&date), -365);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the count does not fit in an `i64`, which takes dates about 25 quadrillion years
+    /// apart, such as [DateStr::MIN] and [DateStr::MAX].
+    pub fn days_between(&self, other: &DateStr) -> i64 {
+        i64::try_from(other.days_from_civil() - self.days_from_civil())
+            .unwrap_or_else(|_e| panic!("the days from {} to {} do not fit in an i64", self, other))
+    }
+
+    /// Returns the signed number of days from `other` to this date, like `self - other`
+    ///
+    /// The `-` operator between two dates subtracts each component instead, so this is the one to
+    /// use for a day count. It's [DateStr::days_between] with the dates swapped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    /// let earlier: DateStr = DateStr::try_from_iso_str("2022-12-25").unwrap();
+    /// assert_eq!(date.sub_days(&earlier), 7);
+    /// assert_eq!(earlier.sub_days(&date), -7);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics like [DateStr::days_between] if the count does not fit in an `i64`.
+    pub fn sub_days(&self, other: &DateStr) -> i64 {
+        other.days_between(self)
+    }
+
+    /// Describes this date relative to `reference` in English, like "in 3 days" or "2 months ago"
+    ///
+    /// The same date is "today", and one day apart is "tomorrow" or "yesterday". Otherwise the
+    /// distance is rounded down to the largest unit that fits: days below a week, weeks below 30
+    /// days, months of 30 days below a year (at most 11, so 360 days are still "11 months"), and
+    /// years of 365 days. It works for any two dates, even those too far apart for
+    /// [DateStr::days_between].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let reference: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let later: DateStr = DateStr::try_from_iso_str("2023-01-03").unwrap();
+    /// assert_eq!(later.humanize_from(&reference), "in 3 days");
+    /// assert_eq!(reference.humanize_from(&later), "3 days ago");
+    /// assert_eq!(reference.humanize_from(&reference), "today");
+    /// ```
+    pub fn humanize_from(&self, reference: &DateStr) -> String {
+        // Counted on an i128, so even DateStr::MIN and DateStr::MAX are described
+        let days: i128 = self.days_from_civil() - reference.days_from_civil();
+        match days {
+            0 => return "today".to_string(),
+            1 => return "tomorrow".to_string(),
+            -1 => return "yesterday".to_string(),
+            _ => {}
+        }
+        let distance: u128 = days.unsigned_abs();
+        let (amount, unit): (u128, &str) = match distance {
+            0..7 => (distance, "day"),
+            7..30 => (distance / 7, "week"),
+            30..365 => ((distance / 30).min(11), "month"),
+            _ => (distance / 365, "year"),
+        };
+        let plural: &str = if amount == 1 { "" } else { "s" };
+        if days > 0 {
+            format!("in {} {}{}", amount, unit, plural)
+        } else {
+            format!("{} {}{} ago", amount, unit, plural)
+        }
+    }
+
+    /// Returns the first date strictly after this one that falls on the given weekday
+    ///
+    /// If this date is already that weekday, the one of the following week is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// // 2022-12-31 is a Saturday
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.next_weekday(Weekday::Monday).to_string(), "2023-01-02");
+    /// assert_eq!(date.next_weekday(Weekday::Saturday).to_string(), "2023-01-07");
+    /// ```
     ///
-    /// The given date must be in ISO-8601 format, that is: YYYY-MM-DD.
+    /// # Panics
+    /// Panics if the resulting year does not fit in a [Year].
+    pub fn next_weekday(&self, weekday: Weekday) -> DateStr {
+        let forward: u8 = (weekday as u8 + 6 - self.weekday() as u8) % 7 + 1;
+        *self + Days(forward as u64)
+    }
+
+    /// Returns the last date strictly before this one that falls on the given weekday
     ///
-    /// I'd recommend using [crate::DateStr::try_from_iso_str] when unsure what the input string will be, since it
-    /// returns a Result with understandable errors.
+    /// If this date is already that weekday, the one of the previous week is returned.
     ///
-    /// # Examples
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// // 2022-12-31 is a Saturday
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.previous_weekday(Weekday::Monday).to_string(), "2022-12-26");
+    /// assert_eq!(date.previous_weekday(Weekday::Saturday).to_string(), "2022-12-24");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the result would be before year 0.
+    pub fn previous_weekday(&self, weekday: Weekday) -> DateStr {
+        let back: u8 = (self.weekday() as u8 + 6 - weekday as u8) % 7 + 1;
+        *self - Days(back as u64)
+    }
+
+    /// Returns the Thursday of this date's ISO week, which is in the ISO week-year
+    ///
+    /// # Panics
+    /// Panics if that Thursday is before year 0 or after year [u64::MAX].
+    fn iso_thursday(&self) -> DateStr {
+        DateStr::civil_from_days(self.days_from_civil() + 3 - self.weekday() as i128)
+            .expect("the ISO week-year does not fit in a Year")
+    }
+
+    /// Returns the ISO-8601 week number, from 1 to 53
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with its first Thursday, so
+    /// the first days of January can be in the last week of the previous year.
+    ///
+    /// # Example
     /// ```rust
     /// # use dates_str::DateStr;
-    /// let date_string: String = String::from("2022-12-31");
-    /// let new_date_from_string: DateStr = DateStr::from_iso_str(date_string);
-    /// let new_date_from_str: DateStr = DateStr::from_iso_str("2022-12-31");
-    /// assert_eq!(new_date_from_str, new_date_from_string);
+    /// assert_eq!(DateStr::try_from_iso_str("2022-12-31").unwrap().iso_week(), 52);
+    /// assert_eq!(DateStr::try_from_iso_str("2021-01-01").unwrap().iso_week(), 53);
     /// ```
-    pub fn from_iso_str<T: ToString>(string: T) -> DateStr {
-        let sep_date: Vec<String> = string
-            .to_string()
-            .split('-')
-            .into_iter()
-            .map(|split| split.to_string())
-            .collect();
-        let year: Year = Year::new(sep_date[0].parse::<u64>().unwrap_or_default());
-        let month: Month = Month::new(sep_date[1].parse::<u8>().unwrap_or_default()).unwrap();
-        if !(1..=12).contains(&month.0) {
-            panic!("Month is out of bounds");
-        }
-        let day: Day = Day::new(sep_date[2].parse::<u8>().unwrap_or_default()).unwrap();
-        let (month_ok, day_ok): (bool, bool) = DateStr::check_date_constraints(month.0, day.0);
-        if !month_ok {
-            panic!("Month {} is out of bounds", month);
+    ///
+    /// # Panics
+    /// Panics for the first days of year 0 and the last days of year [u64::MAX] which belong to
+    /// a week of a year out of range.
+    pub fn iso_week(&self) -> u8 {
+        ((self.iso_thursday().day_of_year() - 1) / 7 + 1) as u8
+    }
+
+    /// Returns the ISO-8601 week-year, the year owning the [DateStr::iso_week] of this date
+    ///
+    /// It's the calendar year except for the first days of January that are in the last week of
+    /// the previous year, and the last days of December that are in the first week of the next.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::try_from_iso_str("2021-01-01").unwrap().iso_week_year(), 2020);
+    /// assert_eq!(DateStr::try_from_iso_str("2024-12-30").unwrap().iso_week_year(), 2025);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics like [DateStr::iso_week].
+    pub fn iso_week_year(&self) -> u64 {
+        self.iso_thursday().year.0
+    }
+
+    /// Returns the ISO-8601 week date, like "2022-W52-6"
+    ///
+    /// It's made of the ISO week-year, which can differ from the calendar year at the start or
+    /// the end of the year, the [DateStr::iso_week] and the weekday, from 1 for Monday to 7 for
+    /// Sunday. The year is padded to four digits like in [DateStr::to_iso_string].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.to_iso_week_date(), "2022-W52-6");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics like [DateStr::iso_week].
+    pub fn to_iso_week_date(&self) -> String {
+        format!(
+            "{:04}-W{:02}-{}",
+            self.iso_week_year(),
+            self.iso_week(),
+            self.weekday() as u8 + 1
+        )
+    }
+
+    /// Counts the days from Monday to Friday in the half-open range from this date to `other`
+    ///
+    /// This date is counted but `other` is not. If `other` is before this date, the days from
+    /// `other` to this date are counted and the result is negative.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let friday: DateStr = DateStr::try_from_iso_str("2022-12-30").unwrap();
+    /// let next_friday: DateStr = DateStr::try_from_iso_str("2023-01-06").unwrap();
+    /// assert_eq!(friday.business_days_between(&next_friday), 5);
+    /// assert_eq!(next_friday.business_days_between(&friday), -5);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the count does not fit in an `i64`, which takes dates about 35 quadrillion years
+    /// apart, such as [DateStr::MIN] and [DateStr::MAX].
+    pub fn business_days_between(&self, other: &DateStr) -> i64 {
+        if other < self {
+            return -other.business_days_between(self);
         }
-        if !day_ok {
-            panic!("Day {} is out of bounds for month {}", day, month);
+        let days: i128 = other.days_from_civil() - self.days_from_civil();
+        let first: usize = self.weekday() as usize;
+        let remaining: i128 = (0..days % 7)
+            .filter(|offset| !Weekday::ALL[(first + *offset as usize) % 7].is_weekend())
+            .count() as i128;
+        i64::try_from(days / 7 * 5 + remaining).unwrap_or_else(|_e| {
+            panic!(
+                "the business days from {} to {} do not fit in an i64",
+                self, other
+            )
+        })
+    }
+
+    /// Returns the week of the month the date falls in, starting at 1
+    ///
+    /// Weeks start on Monday, and the first week is the one containing the 1st of the month, even
+    /// if it's a Sunday.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// // 2023-01-01 is a Sunday, so Monday the 2nd starts the second week
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    /// assert_eq!(date.week_of_month(), 2);
+    /// ```
+    pub fn week_of_month(&self) -> u8 {
+        self.week_of_month_starting(WeekStart::Monday)
+    }
+
+    /// Returns the week of the month the date falls in, starting at 1, with weeks starting on the
+    /// given day
+    ///
+    /// Like [DateStr::week_of_month], which is the same as passing [WeekStart::Monday], the first
+    /// week is the one containing the 1st of the month.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, WeekStart};
+    /// // 2023-01-01 is a Sunday
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-07").unwrap();
+    /// assert_eq!(date.week_of_month_starting(WeekStart::Monday), 2);
+    /// assert_eq!(date.week_of_month_starting(WeekStart::Sunday), 1);
+    /// ```
+    pub fn week_of_month_starting(&self, start: WeekStart) -> u8 {
+        let offset: u8 = (self.start_of_month().weekday() as u8 + 7 - start.first_day() as u8) % 7;
+        (self.day.0 - 1 + offset) / 7 + 1
+    }
+
+    /// Returns the day of the year, from 1 on January 1 to 365 or 366 on December 31
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2024-03-01").unwrap();
+    /// assert_eq!(date.day_of_year(), 61);
+    /// ```
+    pub fn day_of_year(&self) -> u16 {
+        (1..self.month.0)
+            .map(|month| Month(month).length(&self.year) as u16)
+            .sum::<u16>()
+            + self.day.0 as u16
+    }
+
+    /// Builds the date of the given day of the year, starting at 1 on January 1. The inverse of
+    /// [DateStr::day_of_year].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert_eq!(DateStr::from_ordinal(2024, 60).unwrap().to_string(), "2024-02-29");
+    /// assert_eq!(DateStr::from_ordinal(2023, 60).unwrap().to_string(), "2023-03-01");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::InvalidComponent](crate::errors::DateErrors::InvalidComponent) for the
+    /// "ordinal" if it's 0 or past the end of the year, so 366 is only valid in leap years.
+    pub fn from_ordinal(year: u64, ordinal: u16) -> Result<DateStr, errors::DateErrors> {
+        let year: Year = Year::new(year);
+        let mut remaining: u16 = ordinal;
+        for month in (1..=12).map(Month) {
+            let length: u16 = month.length(&year) as u16;
+            if (1..=length).contains(&remaining) {
+                return Ok(DateStr {
+                    year,
+                    month,
+                    day: Day::new_unchecked(remaining as u8),
+                });
+            }
+            remaining = remaining.saturating_sub(length);
         }
-        DateStr { year, month, day }
+        Err(errors::DateErrors::InvalidComponent {
+            component: "ordinal",
+            value: ordinal.to_string(),
+        })
+    }
+
+    /// Returns how many days of the year have passed before this date, 0 on January 1
+    ///
+    /// The same as [DateStr::day_of_year] minus one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-02-01").unwrap();
+    /// assert_eq!(date.days_elapsed_in_year(), 31);
+    /// ```
+    pub fn days_elapsed_in_year(&self) -> u16 {
+        self.day_of_year() - 1
+    }
+
+    /// Returns how many days are left after this date until December 31 of its year, 0 on
+    /// December 31
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-25").unwrap();
+    /// assert_eq!(date.days_left_in_year(), 6);
+    /// ```
+    pub fn days_left_in_year(&self) -> u16 {
+        let year_length: u16 = if self.year.is_leap() { 366 } else { 365 };
+        year_length - self.day_of_year()
     }
 
-    /// Checks if month and day are inside allowed range. Checks if day is within the months day
-    /// too.
+    /// Returns the meteorological season of this date in the northern hemisphere
     ///
-    /// Checks if month is within 1 and 12. Depending on month checks day is within that month's
-    /// days. Returns a tuple with two bools: first is for the month, and second for the day.
-    fn check_date_constraints(month: u8, day: u8) -> (bool, bool) {
-        // TODO: improve this if .. else hell
-        if !(1..=12).contains(&month) {
-            return (false, false);
+    /// Winter is December to February, spring March to May, summer June to August and autumn
+    /// September to November. See [DateStr::southern_season] for the southern hemisphere.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Season};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.season(), Season::Winter);
+    /// ```
+    pub fn season(&self) -> Season {
+        match self.month.0 {
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
+            _ => Season::Winter,
         }
-        if month == 2 {
-            if !(1..=MAX_DAY_FEBR).contains(&day) {
-                (true, false)
-            } else {
-                (true, true)
-            }
-        } else if [1, 3, 5, 7, 8, 10, 12].contains(&month) {
-            if !(1..=31).contains(&day) {
-                (true, false)
-            } else {
-                (true, true)
-            }
-        } else if [4, 6, 9, 11].contains(&month) {
-            if !(1..31).contains(&day) {
-                (true, false)
-            } else {
-                (true, true)
-            }
-        } else {
-            (false, false)
+    }
+
+    /// Returns the meteorological season of this date in the southern hemisphere, the opposite
+    /// of [DateStr::season]
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Season};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// assert_eq!(date.southern_season(), Season::Summer);
+    /// ```
+    pub fn southern_season(&self) -> Season {
+        match self.season() {
+            Season::Winter => Season::Summer,
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
         }
     }
 
-    /// Parse a string to a DateStr struct
+    /// Returns `true` only for February 29
     ///
-    /// Parses a string (or any type implementing the [ToString] trait) to a DateStr struct. This
-    /// function returns a Result enum.
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::try_from_iso_str("2024-02-29").unwrap().is_leap_day());
+    /// assert!(!DateStr::try_from_iso_str("2024-02-28").unwrap().is_leap_day());
+    /// ```
+    pub fn is_leap_day(&self) -> bool {
+        self.month.0 == 2 && self.day.0 == MAX_DAY_FEBR
+    }
+
+    /// Returns `true` if the date is the first day of its month
     ///
-    /// The given date must be in ISO-8601 format, that is: YYYY-MM-DD.
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// assert!(DateStr::try_from_iso_str("2022-12-01").unwrap().is_first_of_month());
+    /// assert!(!DateStr::try_from_iso_str("2022-12-02").unwrap().is_first_of_month());
+    /// ```
+    pub fn is_first_of_month(&self) -> bool {
+        self.day.0 == 1
+    }
+
+    /// Returns `true` if the date is the last day of its month, taking leap years into account
     ///
-    /// # Examples
+    /// # Example
     /// ```rust
     /// # use dates_str::DateStr;
-    /// # use dates_str::errors;
-    /// let date_string: String = String::from("2022-12-31");
-    /// let date_from_string: Result<DateStr, errors::DateErrors> = DateStr::try_from_iso_str(date_string);
-    /// assert!(date_from_string.is_ok());;
+    /// assert!(DateStr::try_from_iso_str("2023-02-28").unwrap().is_last_of_month());
+    /// assert!(!DateStr::try_from_iso_str("2024-02-28").unwrap().is_last_of_month());
     /// ```
+    pub fn is_last_of_month(&self) -> bool {
+        self.day.0 == self.month.length(&self.year)
+    }
+
+    /// Returns the first day of this date's month
     ///
-    /// # Errors
-    /// Since it checks for month first, it will return a DateErrors::InvalidMonth even if the day
-    /// is wrong too, in wich it would return a DateErrors::InvalidDay.
-    pub fn try_from_iso_str<T: ToString>(string: T) -> Result<DateStr, errors::DateErrors> {
-        let sep_date: Vec<String> = string
-            .to_string()
-            .split('-')
-            .into_iter()
-            .map(|split| split.to_string())
-            .collect();
-        let year: u64 = sep_date[0].parse::<u64>().unwrap_or_default();
-        let month: u8 = sep_date[1].parse::<u8>().unwrap_or_default();
-        if !(1..=12).contains(&month) {
-            return Err(errors::DateErrors::InvalidMonth { month });
-        };
-        let day: u8 = sep_date[2].parse::<u8>().unwrap_or_default();
-        if !(1..=31).contains(&day) {
-            return Err(errors::DateErrors::InvalidDay { day });
-        };
-        Ok(DateStr {
-            year: Year::new(year),
-            month: Month::new(month).unwrap(),
-            day: Day::new(day).unwrap(),
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-15").unwrap();
+    /// assert_eq!(date.start_of_month().to_string(), "2022-12-01");
+    /// ```
+    pub fn start_of_month(&self) -> DateStr {
+        DateStr {
+            year: self.year,
+            month: self.month,
+            day: Day::new_unchecked(1),
+        }
+    }
+
+    /// Returns the last day of this date's month, taking leap years into account
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2024-02-10").unwrap();
+    /// assert_eq!(date.end_of_month().to_string(), "2024-02-29");
+    /// ```
+    pub fn end_of_month(&self) -> DateStr {
+        DateStr {
+            year: self.year,
+            month: self.month,
+            day: Day::new_unchecked(self.month.length(&self.year)),
+        }
+    }
+
+    /// Returns the `n`-th day of this date's month that falls on the given weekday, starting at 1,
+    /// or [None] if the month does not have that many
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-01-20").unwrap();
+    /// let third_monday: DateStr = date.nth_weekday_of_month(Weekday::Monday, 3).unwrap();
+    /// assert_eq!(third_monday.to_string(), "2023-01-16");
+    /// assert_eq!(date.nth_weekday_of_month(Weekday::Monday, 6), None);
+    /// ```
+    pub fn nth_weekday_of_month(&self, weekday: Weekday, n: u8) -> Option<DateStr> {
+        let start: DateStr = self.start_of_month();
+        let offset: u8 = (weekday as u8 + 7 - start.weekday() as u8) % 7;
+        let day: u8 = n
+            .checked_sub(1)?
+            .checked_mul(7)?
+            .checked_add(offset + 1)
+            .filter(|day| *day <= self.month.length(&self.year))?;
+        Some(DateStr {
+            day: Day::new_unchecked(day),
+            ..start
         })
     }
-}
 
-/// Display trait implementation for DateStr
-///
-/// Prints the date in ISO-8601 format (YYYY-MM-DD)
-impl Display for DateStr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    /// Returns every day of this date's month that falls on the given weekday, in order
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// let date: DateStr = DateStr::try_from_iso_str("2023-03-10").unwrap();
+    /// let mondays: Vec<String> = date
+    ///     .weekdays_in_month(Weekday::Monday)
+    ///     .iter()
+    ///     .map(|d| d.to_string())
+    ///     .collect();
+    /// assert_eq!(mondays, ["2023-03-06", "2023-03-13", "2023-03-20", "2023-03-27"]);
+    /// ```
+    pub fn weekdays_in_month(&self, weekday: Weekday) -> Vec<DateStr> {
+        (1..=5)
+            .map_while(|n| self.nth_weekday_of_month(weekday, n))
+            .collect()
+    }
+
+    /// Returns the last day of this date's month that falls on the given weekday
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, Weekday};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-05").unwrap();
+    /// assert_eq!(date.last_weekday_of_month(Weekday::Friday).to_string(), "2022-12-30");
+    /// ```
+    pub fn last_weekday_of_month(&self, weekday: Weekday) -> DateStr {
+        let end: DateStr = self.end_of_month();
+        let back: u8 = (end.weekday() as u8 + 7 - weekday as u8) % 7;
+        DateStr {
+            day: Day::new_unchecked(end.day.0 - back),
+            ..end
+        }
+    }
+
+    /// Returns an iterator over the first day of every month from this date's month to `end`'s
+    /// month, both included
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let start: DateStr = DateStr::try_from_iso_str("2022-11-15").unwrap();
+    /// let end: DateStr = DateStr::try_from_iso_str("2023-02-01").unwrap();
+    /// let months: Vec<String> = start.months_until(&end).map(|d| d.to_string()).collect();
+    /// assert_eq!(months, ["2022-11-01", "2022-12-01", "2023-01-01", "2023-02-01"]);
+    /// ```
+    pub fn months_until(&self, end: &DateStr) -> impl Iterator<Item = DateStr> + use<> {
+        let end: DateStr = *end;
+        core::iter::successors(Some(self.start_of_month()), |date| {
+            let (month, wrapped): (Month, bool) = date.month.succ();
+            let year: Year = if wrapped {
+                date.year.checked_add(&Year::new(1)).ok()?
+            } else {
+                date.year
+            };
+            Some(DateStr {
+                year,
+                month,
+                day: Day::new_unchecked(1),
+            })
+        })
+        .take_while(move |date| *date <= end)
+    }
+
+    /// Returns an iterator over every `step`-th day from this date to `end`, both included
+    ///
+    /// A step of 0 gives an empty iterator.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let start: DateStr = DateStr::try_from_iso_str("2022-12-01").unwrap();
+    /// let end: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let fortnights: Vec<String> = start.step_by_days(&end, 14).map(|d| d.to_string()).collect();
+    /// assert_eq!(fortnights, ["2022-12-01", "2022-12-15", "2022-12-29"]);
+    /// ```
+    pub fn step_by_days(&self, end: &DateStr, step: u32) -> impl Iterator<Item = DateStr> + use<> {
+        let end: DateStr = *end;
+        core::iter::successors((step > 0).then_some(*self), move |date| {
+            date.checked_add_days(Days(step as u64))
+        })
+        .take_while(move |date| *date <= end)
     }
 }
 
@@ -390,29 +3109,26 @@ impl DateStr {
     ///
     /// Pass a [DateFormat]. Will output a String with the date formatted how you wanted.
     ///
-    /// Use [crate::DateStr::try_format] for easy error handling
+    /// Deprecated in favor of [crate::DateStr::try_format], which returns the same String in a
+    /// Result. This function calls it and panics with the error message.
     ///
     /// # Example
     /// ```rust
+    /// # #![allow(deprecated)]
     /// # use dates_str::{DateStr, DateFormat};
-    /// let a_date: DateStr = DateStr::from_iso_str("2022-12-29");
+    /// let a_date: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
     /// let a_fmtr: DateFormat = DateFormat::from_string("dd_mm_yyyy", Some('_')).unwrap();
     /// let formatted_date: String = a_date.format(a_fmtr);
     /// println!("{}", formatted_date);
     /// ```
-    /// Above code will output 29-12-2022.
+    /// Above code will output 29_12_2022.
     ///
     /// # Panics
-    /// This function will panic when an invalid [DateFormat] is passed.
-    ///
-    /// To use errors see [crate::DateStr::try_format()]
+    /// A [DateFormat] made with [DateFormat::from_string] is already validated and never panics.
+    /// This function only panics if its `formatter` field was later changed to an invalid one.
+    #[deprecated(note = "use `DateStr::try_format`, which returns an error instead of panicking")]
     pub fn format(&self, fmt: DateFormat) -> String {
-        let self_fmtd: String = fmt
-            .formatter
-            .replace("YYYY", &self.year.to_string())
-            .replace("MM", &self.month.to_string())
-            .replace("DD", &self.day.to_string());
-        self_fmtd
+        self.try_format(fmt).unwrap_or_else(|err| panic!("{}", err))
     }
 
     /// Try to format the date with a custom formatter
@@ -420,21 +3136,140 @@ impl DateStr {
     /// Safe function using the Result enum.
     /// Receives a [DateFormat] struct.
     ///
+    /// The formatter is scanned once from left to right, always taking the longest option at each
+    /// position, so adjacent options such as `YYYYMMDD` or `MMMMD` are each printed once and any
+    /// other character is copied as is.
+    ///
     /// # Example:
     /// ```rust
     /// # use dates_str::{DateStr, DateFormat};
-    /// let a_date: DateStr = DateStr::from_iso_str("2022-12-29");
+    /// let a_date: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
     /// let some_formatter: DateFormat = DateFormat::from_string("dd-mm-yyyy", None).unwrap();
     /// let formatted_date: String = a_date.try_format(some_formatter).unwrap();
     /// println!("{}", formatted_date);
     /// ```
     /// Will output 29-12-2022
     pub fn try_format(&self, fmt: DateFormat) -> Result<String, errors::DateErrors> {
-        let self_fmtd: String = fmt
-            .formatter
-            .replace("YYYY", &self.year.to_string())
-            .replace("MM", &self.month.to_string())
-            .replace("DD", &self.day.to_string());
-        Ok(self_fmtd)
+        Ok(self.render(&DateFormat::tokenize(&fmt.formatter)?, Locale::English))
+    }
+
+    /// Format the date with a formatter string, without building the [DateFormat] first
+    ///
+    /// Takes the same arguments as [DateFormat::from_string], so `sep` is ignored too, and formats
+    /// like [DateStr::try_format].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::DateStr;
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
+    /// assert_eq!(date.format_str("dd-mm-yyyy", None).unwrap(), "29-12-2022");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the errors of [DateFormat::from_string] if the formatter is not valid.
+    pub fn format_str(
+        &self,
+        pattern: &str,
+        sep: Option<char>,
+    ) -> Result<String, errors::DateErrors> {
+        self.try_format(DateFormat::from_string(pattern, sep)?)
+    }
+
+    /// Format the date with a [DateFormat], printing month names in the given language
+    ///
+    /// `MMMM` and `MMM` print the month name and its abbreviation in `locale`, every other
+    /// option prints the same as with [DateStr::try_format]. That includes the weekday names
+    /// (`dddd` and `ddd`) and the `Do` suffix, which stay in English whatever the locale.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, DateFormat, Locale};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let fmt: DateFormat = DateFormat::from_string("d [de] mmmm [de] yyyy", None).unwrap();
+    /// assert_eq!(date.format_localized(&fmt, Locale::Spanish).unwrap(), "31 de diciembre de 2022");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [DateErrors::FormatDateError](crate::errors::DateErrors::FormatDateError), like
+    /// [DateStr::try_format], if the `formatter` field of the [DateFormat] was changed to an
+    /// invalid one.
+    pub fn format_localized(
+        &self,
+        fmt: &DateFormat,
+        locale: Locale,
+    ) -> Result<String, errors::DateErrors> {
+        Ok(self.render(&DateFormat::tokenize(&fmt.formatter)?, locale))
+    }
+
+    /// Writes the date to `w` following the [DateFormat], without allocating
+    ///
+    /// Prints the same as [DateStr::try_format], but the formatter is read and written in a single
+    /// pass straight into the buffer, so no intermediate strings are built.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dates_str::{DateStr, DateFormat};
+    /// let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    /// let fmt: DateFormat = DateFormat::from_string("dd/mm/yyyy", None).unwrap();
+    /// let mut buffer: String = String::from("Date: ");
+    /// date.write_formatted(&mut buffer, &fmt).unwrap();
+    /// assert_eq!(buffer, "Date: 31/12/2022");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the error of `w`, or [core::fmt::Error] if the `formatter` field of the
+    /// [DateFormat] was changed to an invalid one.
+    pub fn write_formatted<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        fmt: &DateFormat,
+    ) -> core::fmt::Result {
+        let mut rest: &str = &fmt.formatter;
+        while !rest.is_empty() {
+            let (token, tail) = DateFormat::next_token(rest).map_err(|_e| core::fmt::Error)?;
+            self.write_token(w, &token, Locale::English)?;
+            rest = tail;
+        }
+        Ok(())
+    }
+
+    /// Writes each formatter token with this date's values, with month names in `locale`
+    fn render(&self, tokens: &[FormatToken], locale: Locale) -> String {
+        let mut self_fmtd: String = String::new();
+        for token in tokens {
+            // Writing to a String never fails
+            let _ = self.write_token(&mut self_fmtd, token, locale);
+        }
+        self_fmtd
+    }
+
+    /// Writes a single formatter token with this date's value
+    fn write_token<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        token: &FormatToken,
+        locale: Locale,
+    ) -> core::fmt::Result {
+        match token {
+            FormatToken::Field(FormatField::Year) => write!(w, "{:04}", self.year.0),
+            FormatToken::Field(FormatField::Month) => write!(w, "{}", self.month),
+            FormatToken::Field(FormatField::MonthPadded) => write!(w, "{:02}", self.month.0),
+            FormatToken::Field(FormatField::MonthName) => {
+                w.write_str(locale.month_name(&self.month))
+            }
+            FormatToken::Field(FormatField::MonthShortName) => {
+                w.write_str(locale.month_short_name(&self.month))
+            }
+            FormatToken::Field(FormatField::Day) => write!(w, "{}", self.day),
+            FormatToken::Field(FormatField::DayPadded) => write!(w, "{:02}", self.day.0),
+            FormatToken::Field(FormatField::DayOrdinal) => {
+                write!(w, "{}{}", self.day, self.day.ordinal_suffix())
+            }
+            FormatToken::Field(FormatField::WeekdayName) => w.write_str(self.weekday().long_name()),
+            FormatToken::Field(FormatField::WeekdayShortName) => {
+                w.write_str(self.weekday().short_name())
+            }
+            FormatToken::Literal(text) => w.write_str(text),
+        }
     }
 }
@@ -2,16 +2,19 @@
 use super::*;
 use crate::errors::DateErrors;
 use crate::impls::*;
+use alloc::borrow::ToOwned;
 
 #[test]
+#[allow(deprecated)]
 fn test_iso_str() {
     let some_date: DateStr = DateStr::from_iso_str("2022-11-16");
     assert_eq!(some_date.to_string(), "2022-11-16".to_owned());
 }
 
 #[test]
+#[allow(deprecated)]
 fn date_fmt() {
-    let some_date: DateStr = DateStr::from_iso_str("2022-12-28");
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-28").unwrap();
     let some_formatter: DateFormat = DateFormat::from_string("dd-mm-yyyy", None).unwrap();
     let fmt_date: String = some_date.format(some_formatter);
     assert_eq!(fmt_date.to_string(), "28-12-2022".to_owned());
@@ -19,7 +22,7 @@ fn date_fmt() {
 
 #[test]
 fn date_lowercase_fmt() {
-    let some_date: DateStr = DateStr::from_iso_str("2022-12-28");
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-28").unwrap();
     let some_formatter: DateFormat = DateFormat::from_string("dd-mm-yyyy", None).unwrap();
     let fmt_date: String = some_date.try_format(some_formatter).unwrap();
     assert_eq!(fmt_date.to_string(), "28-12-2022".to_owned());
@@ -108,3 +111,2354 @@ fn add_one_month() {
     let month2 = Month::new(2).unwrap();
     assert_eq!(month + month2, (Month::new(4).unwrap(), Year::new(0)))
 }
+
+#[test]
+fn new_rejects_april_31() {
+    let date = DateStr::new(
+        Year::new(2023),
+        Month::new(4).unwrap(),
+        Day::new(31).unwrap(),
+    );
+    assert!(matches!(date, Err(DateErrors::InvalidDay { day: 31 })));
+}
+
+#[test]
+fn new_rejects_non_leap_feb_29() {
+    let date = DateStr::new(
+        Year::new(2023),
+        Month::new(2).unwrap(),
+        Day::new(29).unwrap(),
+    );
+    assert!(matches!(date, Err(DateErrors::InvalidDay { day: 29 })));
+}
+
+#[test]
+fn new_accepts_leap_feb_29() {
+    let date = DateStr::new(
+        Year::new(2024),
+        Month::new(2).unwrap(),
+        Day::new(29).unwrap(),
+    );
+    assert!(date.is_ok());
+}
+
+#[test]
+fn new_rejects_invalid_month() {
+    let date = DateStr::new(
+        Year::new(2023),
+        Month::new_unchecked(13),
+        Day::new(1).unwrap(),
+    );
+    assert!(matches!(date, Err(DateErrors::InvalidMonth { month: 13 })));
+}
+
+#[test]
+fn long_str_full_month() {
+    let date: DateStr = DateStr::from_long_str("31 December 2022").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+}
+
+#[test]
+fn long_str_short_month() {
+    let date: DateStr = DateStr::from_long_str("31 DEC 2022").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+}
+
+#[test]
+fn long_str_year_first() {
+    let date: DateStr = DateStr::from_long_str("2022 december 31").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+}
+
+#[test]
+fn long_str_unknown_month() {
+    let date: Result<DateStr, DateErrors> = DateStr::from_long_str("31 Decembre 2022");
+    assert!(matches!(date, Err(DateErrors::InvalidMonth { .. })));
+}
+
+#[test]
+fn year_checked_add_overflow() {
+    let year = Year::new(u64::MAX);
+    assert!(matches!(
+        year.checked_add(&Year::new(1)),
+        Err(DateErrors::InvalidYear(u64::MAX))
+    ));
+    assert_eq!(
+        Year::new(u64::MAX - 1).checked_add(&Year::new(1)).unwrap(),
+        Year::new(u64::MAX)
+    );
+}
+
+#[test]
+fn year_checked_sub_underflow() {
+    assert!(matches!(
+        Year::new(0).checked_sub(&Year::new(1)),
+        Err(DateErrors::InvalidYear(0))
+    ));
+    assert_eq!(
+        Year::new(u64::MAX)
+            .checked_sub(&Year::new(u64::MAX))
+            .unwrap(),
+        Year::new(0)
+    );
+}
+
+#[test]
+fn year_ops_saturate() {
+    assert_eq!(Year::new(u64::MAX) + Year::new(1), Year::new(u64::MAX));
+    assert_eq!(Year::new(1) - Year::new(2), Year::new(0));
+}
+
+#[test]
+fn iso_string_round_trip() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    assert_eq!(date.to_iso_string(), "2022-01-05");
+    assert_eq!(
+        DateStr::try_from_iso_str(date.to_iso_string()).unwrap(),
+        date
+    );
+}
+
+#[test]
+fn iso_string_pads_month_and_day() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-1-5").unwrap();
+    assert_eq!(date.to_iso_string(), "2022-01-05");
+}
+
+#[test]
+fn date_is_before() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-11-30").unwrap();
+    assert!(date.is_before(&DateStr::try_from_iso_str("2022-12-01").unwrap()));
+    assert!(!date.is_before(&DateStr::try_from_iso_str("2022-11-30").unwrap()));
+    assert!(!date.is_before(&DateStr::try_from_iso_str("2021-12-31").unwrap()));
+}
+
+#[test]
+fn date_is_after() {
+    let date: DateStr = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    assert!(date.is_after(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    assert!(!date.is_after(&DateStr::try_from_iso_str("2023-01-01").unwrap()));
+    assert!(!date.is_after(&DateStr::try_from_iso_str("2023-01-02").unwrap()));
+}
+
+#[test]
+fn date_is_same() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert!(date.is_same(&DateStr::try_from_iso_str("2022-12-31").unwrap()));
+    assert!(!date.is_same(&DateStr::try_from_iso_str("2022-12-30").unwrap()));
+}
+
+#[test]
+fn escaped_literal_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-28").unwrap();
+    let some_formatter: DateFormat =
+        DateFormat::from_string("[Year] yyyy [Month] mm [Day] dd", None).unwrap();
+    assert_eq!(
+        some_date.try_format(some_formatter).unwrap(),
+        "Year 2022 Month 12 Day 28".to_owned()
+    );
+}
+
+#[test]
+fn escaped_tokens_are_not_fields() {
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("[YYYY-MM]-DD", None);
+    assert!(some_formatter.is_err());
+}
+
+#[test]
+fn unclosed_escape_error() {
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("[Year YYYY-MM-DD", None);
+    assert!(some_formatter.is_err());
+}
+
+#[test]
+fn years_since_before_anniversary() {
+    let birth: DateStr = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2022-06-14").unwrap();
+    assert_eq!(date.years_since(&birth), 21);
+}
+
+#[test]
+fn years_since_on_anniversary() {
+    let birth: DateStr = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2022-06-15").unwrap();
+    assert_eq!(date.years_since(&birth), 22);
+}
+
+#[test]
+fn years_since_negative() {
+    let birth: DateStr = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2022-06-14").unwrap();
+    assert_eq!(birth.years_since(&date), -21);
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn no_std_parse_and_format() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-28").unwrap();
+    let some_formatter: DateFormat = DateFormat::from_string("dd/mm/yyyy", None).unwrap();
+    assert_eq!(some_date.try_format(some_formatter).unwrap(), "28/12/2022");
+}
+
+#[test]
+fn with_components_valid() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-03-15").unwrap();
+    assert_eq!(date.with_year(2020).unwrap(), DateStr::try_from_iso_str("2020-03-15").unwrap());
+    assert_eq!(date.with_month(2).unwrap(), DateStr::try_from_iso_str("2022-02-15").unwrap());
+    assert_eq!(date.with_day(31).unwrap(), DateStr::try_from_iso_str("2022-03-31").unwrap());
+}
+
+#[test]
+fn with_month_invalidates_day() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-03-31").unwrap();
+    assert!(matches!(
+        date.with_month(2),
+        Err(DateErrors::InvalidDay { day: 31 })
+    ));
+    assert!(matches!(
+        date.with_month(13),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+}
+
+#[test]
+fn with_year_invalidates_leap_day() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    assert!(date.with_year(2023).is_err());
+    assert!(date.with_year(2028).is_ok());
+}
+
+#[test]
+fn try_from_str() {
+    let date: DateStr = DateStr::try_from("2022-12-31").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(date, DateStr::try_from(String::from("2022-12-31")).unwrap());
+}
+
+#[test]
+fn try_from_str_error() {
+    let date: Result<DateStr, DateErrors> = DateStr::try_from("2022-xx-31");
+    assert!(matches!(
+        date,
+        Err(DateErrors::InvalidComponent {
+            component: "month",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn month_length_leap_year() {
+    let year = Year::new(2024);
+    let lengths: Vec<u8> = (1..=12)
+        .map(|m| Month::new(m).unwrap().length(&year))
+        .collect();
+    assert_eq!(lengths, [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+}
+
+#[test]
+fn month_length_non_leap_year() {
+    let year = Year::new(2023);
+    let lengths: Vec<u8> = (1..=12)
+        .map(|m| Month::new(m).unwrap().length(&year))
+        .collect();
+    assert_eq!(lengths, [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+}
+
+#[test]
+fn month_length_century_years() {
+    let february = Month::new(2).unwrap();
+    assert_eq!(february.length(&Year::new(1900)), 28);
+    assert_eq!(february.length(&Year::new(2000)), 29);
+}
+
+#[test]
+#[should_panic]
+fn check_non_leap_feb_29_oobp() {
+    let _date: DateStr = "2023-02-29".to_datestr();
+}
+
+#[test]
+fn clamp_below_min() {
+    let min: DateStr = DateStr::try_from_iso_str("2022-01-01").unwrap();
+    let max: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2021-06-15").unwrap();
+    assert_eq!(date.clamp(&min, &max), min);
+}
+
+#[test]
+fn clamp_above_max() {
+    let min: DateStr = DateStr::try_from_iso_str("2022-01-01").unwrap();
+    let max: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2023-06-15").unwrap();
+    assert_eq!(date.clamp(&min, &max), max);
+}
+
+#[test]
+fn clamp_within_range() {
+    let min: DateStr = DateStr::try_from_iso_str("2022-01-01").unwrap();
+    let max: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let date: DateStr = DateStr::try_from_iso_str("2022-06-15").unwrap();
+    assert_eq!(date.clamp(&min, &max), date);
+    assert_eq!(min.clamp(&min, &max), min);
+    assert_eq!(max.clamp(&min, &max), max);
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "Invalid Month: provided 13")]
+fn from_iso_str_panics_out_of_bounds() {
+    let _date: DateStr = DateStr::from_iso_str("2023-13-40");
+}
+
+#[test]
+fn try_from_iso_str_out_of_bounds() {
+    let date: Result<DateStr, DateErrors> = DateStr::try_from_iso_str("2023-13-40");
+    assert!(matches!(date, Err(DateErrors::InvalidMonth { month: 13 })));
+}
+
+#[test]
+fn unpadded_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let some_formatter: DateFormat = DateFormat::from_string("D/M/YYYY", Some('/')).unwrap();
+    assert_eq!(some_date.try_format(some_formatter).unwrap(), "5/1/2022");
+}
+
+#[test]
+fn padded_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let some_formatter: DateFormat = DateFormat::from_string("DD/MM/YYYY", Some('/')).unwrap();
+    assert_eq!(some_date.try_format(some_formatter).unwrap(), "05/01/2022");
+}
+
+#[test]
+fn weekday_calculation() {
+    let dates = [
+        ("1970-01-01", Weekday::Thursday),
+        ("2000-02-29", Weekday::Tuesday),
+        ("2022-12-31", Weekday::Saturday),
+        ("2023-01-02", Weekday::Monday),
+        ("1969-12-31", Weekday::Wednesday),
+    ];
+    for (date, weekday) in dates {
+        assert_eq!(DateStr::try_from_iso_str(date).unwrap().weekday(), weekday);
+    }
+}
+
+#[test]
+fn business_days_across_weekend() {
+    // Thursday to the next Thursday skips Saturday and Sunday
+    let thursday: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
+    let next_thursday: DateStr = DateStr::try_from_iso_str("2023-01-05").unwrap();
+    assert_eq!(thursday.business_days_between(&next_thursday), 5);
+    let monday: DateStr = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    assert_eq!(thursday.business_days_between(&monday), 2);
+}
+
+#[test]
+fn business_days_backwards() {
+    let thursday: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
+    let monday: DateStr = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    assert_eq!(monday.business_days_between(&thursday), -2);
+    assert_eq!(monday.business_days_between(&monday), 0);
+}
+
+#[test]
+fn month_bounds_leap_february() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-02-10").unwrap();
+    assert_eq!(date.start_of_month(), DateStr::try_from_iso_str("2024-02-01").unwrap());
+    assert_eq!(date.end_of_month(), DateStr::try_from_iso_str("2024-02-29").unwrap());
+}
+
+#[test]
+fn month_bounds_non_leap_february() {
+    let date: DateStr = DateStr::try_from_iso_str("2023-02-10").unwrap();
+    assert_eq!(date.start_of_month(), DateStr::try_from_iso_str("2023-02-01").unwrap());
+    assert_eq!(date.end_of_month(), DateStr::try_from_iso_str("2023-02-28").unwrap());
+}
+
+#[test]
+fn month_bounds_30_day_month() {
+    let date: DateStr = DateStr::try_from_iso_str("2023-04-30").unwrap();
+    assert_eq!(date.start_of_month(), DateStr::try_from_iso_str("2023-04-01").unwrap());
+    assert_eq!(date.end_of_month(), date);
+}
+
+#[test]
+fn rfc3339_string() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(date.to_rfc3339(), "2022-12-31T00:00:00Z");
+}
+
+#[test]
+fn unpadded_iso_components() {
+    let unpadded: DateStr = DateStr::try_from_iso_str("2022-3-5").unwrap();
+    let padded: DateStr = DateStr::try_from_iso_str("2022-03-05").unwrap();
+    assert_eq!(unpadded, padded);
+    assert_eq!(unpadded.to_string(), "2022-03-05");
+}
+
+#[test]
+fn mixed_padding_iso_components() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-03-5").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-3-05").unwrap());
+    assert!(DateStr::try_from_iso_str("2022-2-30").is_err());
+}
+
+#[test]
+fn month_names() {
+    let december = Month::new(12).unwrap();
+    assert_eq!(december.name(), "December");
+    assert_eq!(december.short_name(), "Dec");
+    assert_eq!(Month::new(1).unwrap().name(), "January");
+    assert_eq!(Month::new(9).unwrap().short_name(), "Sep");
+}
+
+#[test]
+fn month_name_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-05").unwrap();
+    let long: DateFormat = DateFormat::from_string("D MMMM YYYY", None).unwrap();
+    let short: DateFormat = DateFormat::from_string("mmm dd, yyyy", None).unwrap();
+    assert_eq!(some_date.try_format(long).unwrap(), "5 December 2022");
+    assert_eq!(some_date.try_format(short).unwrap(), "Dec 05, 2022");
+}
+
+#[test]
+fn weekday_index_round_trip() {
+    for index in 0..7 {
+        let weekday: Weekday = Weekday::from_index(index).unwrap();
+        assert_eq!(weekday as u8, index);
+    }
+    assert_eq!(Weekday::from_index(0), Some(Weekday::Monday));
+    assert_eq!(Weekday::from_index(6), Some(Weekday::Sunday));
+    assert_eq!(Weekday::from_index(7), None);
+}
+
+#[test]
+fn weekday_names() {
+    assert_eq!(Weekday::Wednesday.long_name(), "Wednesday");
+    assert_eq!(Weekday::Wednesday.short_name(), "Wed");
+    assert_eq!(Weekday::from_index(6).unwrap().long_name(), "Sunday");
+}
+
+#[test]
+fn weekday_name_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let some_formatter: DateFormat =
+        DateFormat::from_string("dddd, DD MMMM YYYY", None).unwrap();
+    assert_eq!(
+        some_date.try_format(some_formatter).unwrap(),
+        "Saturday, 31 December 2022"
+    );
+}
+
+#[test]
+fn weekday_short_name_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    let some_formatter: DateFormat = DateFormat::from_string("ddd DD/MM/YYYY", None).unwrap();
+    assert_eq!(some_date.try_format(some_formatter).unwrap(), "Mon 02/01/2023");
+}
+
+#[test]
+fn weekday_is_not_a_day() {
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("dddd MM YYYY", None);
+    assert!(some_formatter.is_err());
+}
+
+#[test]
+fn checked_sub_days_across_boundaries() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-03-01").unwrap();
+    assert_eq!(
+        date.checked_sub_days(Days(1)),
+        Some(DateStr::try_from_iso_str("2024-02-29").unwrap())
+    );
+    assert_eq!(
+        date.checked_sub_days(Days(366)),
+        Some(DateStr::try_from_iso_str("2023-03-01").unwrap())
+    );
+    assert_eq!(date.checked_sub_days(Days(0)), Some(date));
+}
+
+#[test]
+fn checked_sub_days_near_year_zero() {
+    let date: DateStr = DateStr::try_from_iso_str("0000-01-02").unwrap();
+    assert_eq!(
+        date.checked_sub_days(Days(1)),
+        Some(DateStr::try_from_iso_str("0000-01-01").unwrap())
+    );
+    assert_eq!(date.checked_sub_days(Days(2)), None);
+    assert_eq!(date.checked_sub_days(Days(u64::MAX)), None);
+}
+
+#[test]
+fn ordinal_day_fmt() {
+    let expected = [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (23, "23rd"),
+        (31, "31st"),
+    ];
+    for (day, ordinal) in expected {
+        let some_date: DateStr = DateStr::try_from_iso_str("2022-12-01")
+            .unwrap()
+            .with_day(day)
+            .unwrap();
+        let some_formatter: DateFormat = DateFormat::from_string("Do MMMM YYYY", None).unwrap();
+        assert_eq!(
+            some_date.try_format(some_formatter).unwrap(),
+            format!("{} December 2022", ordinal)
+        );
+    }
+}
+
+#[test]
+fn week_of_month_starting_sunday() {
+    // January 2023 starts on a Sunday
+    let weeks = [(1, 1), (2, 2), (7, 2), (8, 2), (9, 3), (31, 6)];
+    for (day, week) in weeks {
+        let date: DateStr = DateStr::try_from_iso_str("2023-01-01")
+            .unwrap()
+            .with_day(day)
+            .unwrap();
+        assert_eq!(date.week_of_month(), week, "day {}", day);
+    }
+}
+
+#[test]
+fn week_of_month_starting_monday() {
+    // May 2023 starts on a Monday
+    let weeks = [(1, 1), (7, 1), (8, 2), (31, 5)];
+    for (day, week) in weeks {
+        let date: DateStr = DateStr::try_from_iso_str("2023-05-01")
+            .unwrap()
+            .with_day(day)
+            .unwrap();
+        assert_eq!(date.week_of_month(), week, "day {}", day);
+    }
+}
+
+#[test]
+fn months_until_across_year() {
+    let start: DateStr = DateStr::try_from_iso_str("2022-11-15").unwrap();
+    let end: DateStr = DateStr::try_from_iso_str("2023-02-01").unwrap();
+    let months: Vec<DateStr> = start.months_until(&end).collect();
+    let expected: Vec<DateStr> = ["2022-11-01", "2022-12-01", "2023-01-01", "2023-02-01"]
+        .iter()
+        .map(|date| DateStr::try_from_iso_str(date).unwrap())
+        .collect();
+    assert_eq!(months, expected);
+}
+
+#[test]
+fn months_until_empty_when_end_is_earlier() {
+    let start: DateStr = DateStr::try_from_iso_str("2022-11-15").unwrap();
+    let end: DateStr = DateStr::try_from_iso_str("2022-10-31").unwrap();
+    assert_eq!(start.months_until(&end).count(), 0);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_round_trip() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    let naive: chrono::NaiveDate = date.try_into().unwrap();
+    assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    assert_eq!(DateStr::try_from(naive).unwrap(), date);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_negative_year() {
+    let naive: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+    assert!(matches!(
+        DateStr::try_from(naive),
+        Err(DateErrors::NegativeYear(-44))
+    ));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_out_of_range_year() {
+    let date: DateStr = DateStr::try_from_iso_str("9999999999-01-01").unwrap();
+    assert!(matches!(
+        chrono::NaiveDate::try_from(date),
+        Err(DateErrors::InvalidYear(9999999999))
+    ));
+    let date: DateStr = DateStr::try_from_iso_str("300000-01-01").unwrap();
+    assert!(chrono::NaiveDate::try_from(date).is_err());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_round_trip() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    let time_date: time::Date = date.try_into().unwrap();
+    assert_eq!(
+        time_date,
+        time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap()
+    );
+    assert_eq!(DateStr::try_from(time_date).unwrap(), date);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_negative_year() {
+    let time_date: time::Date =
+        time::Date::from_calendar_date(-44, time::Month::March, 15).unwrap();
+    assert!(matches!(
+        DateStr::try_from(time_date),
+        Err(DateErrors::NegativeYear(-44))
+    ));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_out_of_range_year() {
+    let date: DateStr = DateStr::try_from_iso_str("9999999999-01-01").unwrap();
+    assert!(matches!(
+        time::Date::try_from(date),
+        Err(DateErrors::InvalidYear(9999999999))
+    ));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_year_10000() {
+    let last: DateStr = DateStr::try_from_iso_str("9999-12-31").unwrap();
+    assert!(time::Date::try_from(last).is_ok());
+    let date: DateStr = DateStr::try_from_iso_str("10000-01-01").unwrap();
+    assert!(matches!(
+        time::Date::try_from(date),
+        Err(DateErrors::InvalidYear(10000))
+    ));
+}
+
+#[test]
+fn compact_fmt() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let some_formatter: DateFormat = DateFormat::from_string("YYYYMMDD", None).unwrap();
+    assert_eq!(some_date.try_format(some_formatter).unwrap(), "20220105");
+}
+
+#[test]
+fn duplicated_token_error() {
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("YYYY-MM-DD-DD", None);
+    assert!(some_formatter.is_err());
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("YYYY-MM-D-Do", None);
+    assert!(some_formatter.is_err());
+}
+
+#[test]
+fn unknown_fragment_error() {
+    let some_formatter: Result<DateFormat, DateErrors> =
+        DateFormat::from_string("YYYY-MM-DD-XX", None);
+    assert!(some_formatter.is_err());
+    let some_formatter: Result<DateFormat, DateErrors> = DateFormat::from_string("YY-MM-DD", None);
+    assert!(some_formatter.is_err());
+}
+
+#[test]
+fn compact_string() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    assert_eq!(date.format_compact(), "20220105");
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(date.format_compact(), "20221231");
+}
+
+#[test]
+fn parse_auto_separators() {
+    let expected: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    for input in ["2022-12-31", "2022/12/31", "2022.12.31", "2022 12 31"] {
+        assert_eq!(DateStr::parse_auto(input).unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn parse_auto_errors() {
+    assert!(matches!(
+        DateStr::parse_auto("2022_12_31"),
+        Err(DateErrors::InvalidParsing(_))
+    ));
+    assert!(matches!(
+        DateStr::parse_auto("2022/13/31"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+}
+
+#[test]
+fn component_error_names_year() {
+    let error: DateErrors = DateStr::try_from_iso_str("yyyy-12-31").unwrap_err();
+    assert_eq!(error.to_string(), "failed to parse year in 'yyyy-12-31'");
+}
+
+#[test]
+fn component_error_names_month() {
+    let error: DateErrors = DateStr::try_from_iso_str("2022-xx-31").unwrap_err();
+    assert_eq!(error.to_string(), "failed to parse month in '2022-xx-31'");
+}
+
+#[test]
+fn component_error_names_day() {
+    let error: DateErrors = DateStr::try_from(String::from("2022-12-3x")).unwrap_err();
+    assert!(matches!(
+        error,
+        DateErrors::InvalidComponent {
+            component: "day",
+            ..
+        }
+    ));
+    assert_eq!(error.to_string(), "failed to parse day in '2022-12-3x'");
+}
+
+#[test]
+fn add_assign_days_in_loop() {
+    let mut date: DateStr = DateStr::try_from_iso_str("2024-02-27").unwrap();
+    let mut seen: Vec<String> = Vec::new();
+    for _ in 0..3 {
+        date += Days(1);
+        seen.push(date.to_string());
+    }
+    assert_eq!(seen, ["2024-02-28", "2024-02-29", "2024-03-01"]);
+}
+
+#[test]
+fn sub_assign_days_across_year() {
+    let mut date: DateStr = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    date -= Days(2);
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(date + Days(2), DateStr::try_from_iso_str("2023-01-02").unwrap());
+}
+
+#[test]
+#[should_panic]
+fn sub_days_before_year_zero() {
+    let _date: DateStr = DateStr::try_from_iso_str("0000-01-01").unwrap() - Days(1);
+}
+
+#[test]
+fn min_max_sentinels() {
+    assert!(DateStr::MIN < DateStr::MAX);
+    assert_eq!(DateStr::MIN, DateStr::try_from_iso_str("0000-01-01").unwrap());
+    assert_eq!(DateStr::MAX.to_string(), format!("{}-12-31", u64::MAX));
+    assert_eq!(DateStr::MIN.checked_sub_days(Days(1)), None);
+    assert_eq!(DateStr::MAX.checked_add_days(Days(1)), None);
+}
+
+#[test]
+fn try_from_missing_parts() {
+    for (input, parts) in [("2022", 1), ("2022-12", 2), ("2022-12-31-01", 4), ("", 1)] {
+        let date: Result<DateStr, DateErrors> = DateStr::try_from(input);
+        assert!(
+            matches!(&date, Err(DateErrors::WrongPartCount { expected: 3, found }) if *found == parts),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn try_from_empty_parts() {
+    let date: Result<DateStr, DateErrors> = DateStr::try_from("--");
+    assert!(matches!(
+        date,
+        Err(DateErrors::InvalidComponent {
+            component: "year",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn us_str_parse() {
+    let date: DateStr = DateStr::from_us_str("12/31/2022").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(
+        DateStr::from_us_str("1/5/2022").unwrap(),
+        DateStr::try_from_iso_str("2022-01-05").unwrap()
+    );
+}
+
+#[test]
+fn us_str_rejects_month_out_of_range() {
+    assert!(matches!(
+        DateStr::from_us_str("13/01/2022"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+    assert!(DateStr::from_us_str("2022/12/31").is_err());
+    assert!(DateStr::from_us_str("12/32/2022").is_err());
+}
+
+#[test]
+fn add_years_clamps_leap_day() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    assert_eq!(date.add_years(1), DateStr::try_from_iso_str("2025-02-28").unwrap());
+    assert_eq!(date.add_years(4), DateStr::try_from_iso_str("2028-02-29").unwrap());
+}
+
+#[test]
+fn add_years_ordinary_date() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(date.add_years(3), DateStr::try_from_iso_str("2025-12-31").unwrap());
+    assert_eq!(date.add_years(0), date);
+}
+
+#[test]
+fn display_iso_form() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    assert_eq!(format!("{}", date), "2022-01-05");
+}
+
+#[test]
+fn display_alternate_long_form() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(format!("{:#}", date), "31 December 2022");
+    let date: DateStr = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    assert_eq!(format!("{:#}", date), "5 January 2022");
+}
+
+#[test]
+fn succ_n_across_two_years() {
+    let date: DateStr = DateStr::try_from_iso_str("2022-12-15").unwrap();
+    assert_eq!(date.succ_n(400), DateStr::try_from_iso_str("2024-01-19").unwrap());
+    assert_eq!(date.succ_n(0), date);
+}
+
+#[test]
+fn pred_n_across_two_years() {
+    let date: DateStr = DateStr::try_from_iso_str("2024-01-19").unwrap();
+    assert_eq!(date.pred_n(400), DateStr::try_from_iso_str("2022-12-15").unwrap());
+    assert_eq!(date.succ_n(u32::MAX).pred_n(u32::MAX), date);
+}
+
+#[test]
+fn leap_day_check() {
+    assert!(DateStr::try_from_iso_str("2024-02-29").unwrap().is_leap_day());
+    assert!(!DateStr::try_from_iso_str("2023-02-28").unwrap().is_leap_day());
+    assert!(!DateStr::try_from_iso_str("2024-02-28").unwrap().is_leap_day());
+    assert!(!DateStr::try_from_iso_str("2024-03-29").unwrap().is_leap_day());
+}
+
+#[test]
+fn parse_date_part_strips_time_and_zone() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(DateStr::parse_date_part("2022-12-31T23:59:59Z").unwrap(), date);
+    assert_eq!(DateStr::parse_date_part("2022-12-31+02:00").unwrap(), date);
+    assert_eq!(DateStr::parse_date_part("2022-12-31Z").unwrap(), date);
+    assert_eq!(DateStr::parse_date_part("2022-12-31-05:00").unwrap(), date);
+}
+
+#[test]
+fn parse_date_part_without_suffix() {
+    let date = DateStr::parse_date_part("2022-12-31").unwrap();
+    assert_eq!(date.to_string(), "2022-12-31");
+    assert!(DateStr::parse_date_part("T23:59:59Z").is_err());
+}
+
+#[test]
+fn diff_borrows_across_months() {
+    let start = DateStr::try_from_iso_str("2020-01-15").unwrap();
+    let end = DateStr::try_from_iso_str("2022-03-10").unwrap();
+    assert_eq!(
+        start.diff(&end),
+        DateDiff {
+            years: 2,
+            months: 1,
+            days: 23
+        }
+    );
+    // February 2020 has 29 days to borrow from
+    let leap_end = DateStr::try_from_iso_str("2020-03-10").unwrap();
+    assert_eq!(
+        DateStr::try_from_iso_str("2020-02-15").unwrap().diff(&leap_end),
+        DateDiff {
+            years: 0,
+            months: 0,
+            days: 24
+        }
+    );
+}
+
+#[test]
+fn diff_clamps_to_month_end() {
+    let start = DateStr::try_from_iso_str("2022-01-31").unwrap();
+    let end = DateStr::try_from_iso_str("2022-03-01").unwrap();
+    assert_eq!(
+        end.diff(&start),
+        DateDiff {
+            years: 0,
+            months: 1,
+            days: 1
+        }
+    );
+    assert_eq!(
+        start.diff(&start),
+        DateDiff {
+            years: 0,
+            months: 0,
+            days: 0
+        }
+    );
+}
+
+#[test]
+fn date_range_iterates_days() {
+    let range = DateRange {
+        start: DateStr::try_from_iso_str("2024-02-27").unwrap(),
+        end: DateStr::try_from_iso_str("2024-03-02").unwrap(),
+    };
+    let days: Vec<DateStr> = (&range).into_iter().collect();
+    assert_eq!(days.len(), 5);
+    assert_eq!(days[0], range.start);
+    assert_eq!(days[2].to_string(), "2024-02-29");
+    assert_eq!(days[4], range.end);
+    let mut count = 0;
+    for _day in &range {
+        count += 1;
+    }
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn date_range_reversed_is_empty() {
+    let range = DateRange {
+        start: DateStr::try_from_iso_str("2022-12-31").unwrap(),
+        end: DateStr::try_from_iso_str("2022-12-30").unwrap(),
+    };
+    assert_eq!((&range).into_iter().count(), 0);
+}
+
+#[test]
+fn parse_many_splits_results() {
+    let input = "2022-12-31\n2022-13-01\n\n  2023-01-01  \n2023-xx-01\n";
+    let (dates, errors) = DateStr::parse_many(input);
+    assert_eq!(
+        dates,
+        [
+            DateStr::try_from_iso_str("2022-12-31").unwrap(),
+            DateStr::try_from_iso_str("2023-01-01").unwrap()
+        ]
+    );
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], DateErrors::InvalidMonth { month: 13 }));
+    assert!(matches!(errors[1], DateErrors::InvalidComponent { component: "month", .. }));
+}
+
+#[test]
+fn epoch_constant() {
+    assert_eq!(DateStr::EPOCH, DateStr::try_from_iso_str("1970-01-01").unwrap());
+    assert_eq!(DateStr::EPOCH.weekday(), Weekday::Thursday);
+}
+
+#[test]
+fn parse_lossy_rejects_garbage() {
+    let garbage = [
+        "",
+        "-",
+        "--",
+        "---",
+        "2022",
+        "2022-12",
+        "2022-12-31-01",
+        "2022/12/31",
+        "-2022-12-31",
+        "2022--12-31",
+        "2022-12--31",
+        "2022-256-01",
+        "2022-12-256",
+        "99999999999999999999999-01-01",
+        "２０２２-１２-３１",
+        "2022-12-31\u{0}",
+        "日付-月-日",
+        "2022-02-30",
+        "2022-00-10",
+        "2022-10-00",
+    ];
+    for input in garbage {
+        assert_eq!(DateStr::parse_lossy(input), None, "{:?}", input);
+    }
+    assert!(DateStr::parse_lossy("2022-12-31").is_some());
+}
+
+#[test]
+fn day_add_carries_past_thirty() {
+    let day = |value| Day::new(value).unwrap();
+    assert_eq!(day(15) + day(15), (day(30), Month::new_unchecked(0)));
+    assert_eq!(day(15) + day(16), (day(1), Month::new_unchecked(1)));
+    assert_eq!(day(30) + day(30), (day(30), Month::new_unchecked(1)));
+    assert_eq!(day(31) + day(30), (day(1), Month::new_unchecked(2)));
+}
+
+#[test]
+fn month_add_carries_past_twelve() {
+    let month = |value| Month::new(value).unwrap();
+    assert_eq!(month(6) + month(6), (month(12), Year::new(0)));
+    assert_eq!(month(6) + month(7), (month(1), Year::new(1)));
+    assert_eq!(month(12) + month(12), (month(12), Year::new(1)));
+}
+
+#[test]
+fn julian_day_known_values() {
+    assert_eq!(DateStr::try_from_iso_str("2000-01-01").unwrap().to_julian_day(), 2451545);
+    assert_eq!(DateStr::EPOCH.to_julian_day(), 2440588);
+    assert_eq!(DateStr::MIN.to_julian_day(), 1721060);
+    assert_eq!(DateStr::from_julian_day(2451545).to_string(), "2000-01-01");
+}
+
+#[test]
+fn julian_day_round_trip() {
+    for date in ["0000-01-01", "1582-10-15", "2024-02-29", "9999-12-31"] {
+        let date = DateStr::try_from_iso_str(date).unwrap();
+        assert_eq!(DateStr::from_julian_day(date.to_julian_day()), date);
+    }
+}
+
+#[test]
+#[should_panic]
+fn julian_day_before_year_zero() {
+    DateStr::from_julian_day(1721059);
+}
+
+#[test]
+fn last_friday_of_month() {
+    let december = DateStr::try_from_iso_str("2022-12-05").unwrap();
+    assert_eq!(december.last_weekday_of_month(Weekday::Friday).to_string(), "2022-12-30");
+    // 2022-12-31 is a Saturday
+    assert_eq!(december.last_weekday_of_month(Weekday::Saturday).to_string(), "2022-12-31");
+    let february = DateStr::try_from_iso_str("2024-02-10").unwrap();
+    assert_eq!(february.last_weekday_of_month(Weekday::Friday).to_string(), "2024-02-23");
+    assert_eq!(february.last_weekday_of_month(Weekday::Thursday).to_string(), "2024-02-29");
+}
+
+#[test]
+fn nth_weekday_of_month_found() {
+    let date = DateStr::try_from_iso_str("2023-01-20").unwrap();
+    assert_eq!(
+        date.nth_weekday_of_month(Weekday::Monday, 3).unwrap().to_string(),
+        "2023-01-16"
+    );
+    // 2023-01-01 is a Sunday
+    assert_eq!(
+        date.nth_weekday_of_month(Weekday::Sunday, 1).unwrap().to_string(),
+        "2023-01-01"
+    );
+    assert_eq!(
+        date.nth_weekday_of_month(Weekday::Tuesday, 5).unwrap().to_string(),
+        "2023-01-31"
+    );
+}
+
+#[test]
+fn nth_weekday_of_month_missing() {
+    let date = DateStr::try_from_iso_str("2023-01-20").unwrap();
+    assert_eq!(date.nth_weekday_of_month(Weekday::Friday, 5), None);
+    assert_eq!(date.nth_weekday_of_month(Weekday::Friday, 0), None);
+    assert_eq!(date.nth_weekday_of_month(Weekday::Friday, 255), None);
+}
+
+#[test]
+fn valid_iso_strings() {
+    assert!(DateStr::is_valid_iso_string("2022-12-31"));
+    assert!(DateStr::is_valid_iso_string("2022-1-5"));
+    assert!(DateStr::is_valid_iso_string("2024-02-29"));
+}
+
+#[test]
+fn invalid_iso_strings() {
+    assert!(!DateStr::is_valid_iso_string("2022/12/31"));
+    assert!(!DateStr::is_valid_iso_string("2022-12-31-01"));
+    assert!(!DateStr::is_valid_iso_string("2022-13-01"));
+    assert!(!DateStr::is_valid_iso_string("2022-04-31"));
+    assert!(!DateStr::is_valid_iso_string("2023-02-29"));
+    assert!(!DateStr::is_valid_iso_string("2022-xx-31"));
+    assert!(!DateStr::is_valid_iso_string(""));
+}
+
+#[test]
+fn format_localized_month_names() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let long = DateFormat::from_string("mmmm d, yyyy", None).unwrap();
+    assert_eq!(date.format_localized(&long, Locale::Spanish).unwrap(), "diciembre 31, 2022");
+    assert_eq!(date.format_localized(&long, Locale::English).unwrap(), "December 31, 2022");
+    let short = DateFormat::from_string("dd mmm yyyy", None).unwrap();
+    assert_eq!(date.format_localized(&short, Locale::Spanish).unwrap(), "31 dic 2022");
+    assert_eq!(date.format_localized(&short, Locale::default()).unwrap(), "31 Dec 2022");
+}
+
+#[test]
+fn days_between_dates() {
+    let date = DateStr::try_from_iso_str("2024-02-28").unwrap();
+    assert_eq!(date.days_between(&DateStr::try_from_iso_str("2024-03-01").unwrap()), 2);
+    assert_eq!(date.days_between(&date), 0);
+    assert_eq!(DateStr::EPOCH.days_between(&DateStr::MIN), -719528);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tomorrow_and_yesterday_around_today() {
+    // Each call reads the clock, so a run crossing midnight UTC sees the later calls a day ahead
+    let today = DateStr::today();
+    let tomorrow = today.days_between(&DateStr::tomorrow());
+    let yesterday = today.days_between(&DateStr::yesterday());
+    assert!((1..=2).contains(&tomorrow), "{}", tomorrow);
+    assert!((-1..=0).contains(&yesterday), "{}", yesterday);
+}
+
+#[test]
+fn btree_map_keys_are_chronological() {
+    let mut events: alloc::collections::BTreeMap<DateStr, &str> = alloc::collections::BTreeMap::new();
+    for (date, event) in [
+        ("2023-01-01", "new year"),
+        ("2022-12-25", "christmas"),
+        ("2022-02-28", "end of february"),
+        ("2100-01-01", "next century"),
+        ("2022-12-31", "new year's eve"),
+        ("0999-12-31", "last day of 999"),
+    ] {
+        events.insert(DateStr::try_from_iso_str(date).unwrap(), event);
+    }
+    let keys: Vec<String> = events.keys().map(|date| date.to_string()).collect();
+    assert_eq!(
+        keys,
+        ["0999-12-31", "2022-02-28", "2022-12-25", "2022-12-31", "2023-01-01", "2100-01-01"]
+    );
+    let after_christmas: Vec<&str> = events
+        .range(DateStr::try_from_iso_str("2022-12-26").unwrap()..)
+        .map(|(_date, event)| *event)
+        .collect();
+    assert_eq!(after_christmas, ["new year's eve", "new year", "next century"]);
+}
+
+#[test]
+fn month_succ_carries_into_year() {
+    assert_eq!(Month::new(12).unwrap().succ(), (Month::new(1).unwrap(), true));
+    assert_eq!(Month::new(6).unwrap().succ(), (Month::new(7).unwrap(), false));
+    assert_eq!(Month::new(11).unwrap().succ(), (Month::new(12).unwrap(), false));
+}
+
+#[test]
+fn next_weekday_skips_same_day() {
+    // 2023-01-02 is a Monday
+    let monday = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    assert_eq!(monday.next_weekday(Weekday::Monday).to_string(), "2023-01-09");
+    assert_eq!(monday.next_weekday(Weekday::Tuesday).to_string(), "2023-01-03");
+    assert_eq!(monday.next_weekday(Weekday::Sunday).to_string(), "2023-01-08");
+}
+
+#[test]
+fn previous_weekday_skips_same_day() {
+    let monday = DateStr::try_from_iso_str("2023-01-02").unwrap();
+    assert_eq!(monday.previous_weekday(Weekday::Monday).to_string(), "2022-12-26");
+    assert_eq!(monday.previous_weekday(Weekday::Sunday).to_string(), "2023-01-01");
+    assert_eq!(monday.previous_weekday(Weekday::Tuesday).to_string(), "2022-12-27");
+}
+
+#[test]
+fn format_error_names_bad_token() {
+    let unknown = DateFormat::from_string("yyyy-mm-dd q", None).unwrap_err();
+    assert!(matches!(&unknown, DateErrors::FormatDateError(token) if token == "Q"));
+    assert_eq!(unknown.to_string(), "Format not recognized: 'Q'");
+    let repeated = DateFormat::from_string("yyyy-mm-dd mmm", None).unwrap_err();
+    assert!(repeated.to_string().contains("'MMM'"));
+    let unclosed = DateFormat::from_string("yyyy-mm-dd [at", None).unwrap_err();
+    assert!(unclosed.to_string().contains("'[at'"));
+    let missing = DateFormat::from_string("yyyy-mm", None).unwrap_err();
+    assert!(missing.to_string().contains("'YYYY-MM'"));
+}
+
+#[test]
+fn try_format_error_names_bad_token() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let fmt = DateFormat {
+        formatter: "YYYY-MM-DD x".to_owned(),
+    };
+    let error = date.try_format(fmt).unwrap_err();
+    assert!(matches!(error, DateErrors::FormatDateError(token) if token == "x"));
+}
+
+#[test]
+fn checked_add_months_rolls_and_clamps() {
+    let date = DateStr::try_from_iso_str("2022-10-31").unwrap();
+    assert_eq!(date.checked_add_months(0).unwrap(), date);
+    assert_eq!(date.checked_add_months(3).unwrap().to_string(), "2023-01-31");
+    assert_eq!(date.checked_add_months(4).unwrap().to_string(), "2023-02-28");
+    assert_eq!(date.checked_add_months(16).unwrap().to_string(), "2024-02-29");
+}
+
+#[test]
+fn checked_add_months_overflow() {
+    let last_year = DateStr::new(Year::new(u64::MAX), Month::new(11).unwrap(), Day::new(15).unwrap())
+        .unwrap();
+    assert_eq!(last_year.checked_add_months(1).unwrap(), last_year.with_month(12).unwrap());
+    assert_eq!(last_year.checked_add_months(2), None);
+    assert_eq!(DateStr::MAX.checked_add_months(u32::MAX), None);
+}
+
+#[test]
+fn date_equals_iso_str() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert!(date == "2022-12-31");
+    assert!(date == *"2022-12-31");
+    assert_eq!(date, "2022-12-31");
+}
+
+#[test]
+fn date_differs_from_other_strs() {
+    let date = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    assert!(date != "2022-1-5");
+    assert!(date != "2022-01-06");
+    assert!(date != "2022/01/05");
+    assert!(date != "");
+}
+
+#[test]
+fn day_of_year_count() {
+    assert_eq!(DateStr::try_from_iso_str("2023-01-01").unwrap().day_of_year(), 1);
+    assert_eq!(DateStr::try_from_iso_str("2023-12-31").unwrap().day_of_year(), 365);
+    assert_eq!(DateStr::try_from_iso_str("2024-12-31").unwrap().day_of_year(), 366);
+}
+
+#[test]
+fn days_left_in_year_count() {
+    assert_eq!(DateStr::try_from_iso_str("2023-01-01").unwrap().days_left_in_year(), 364);
+    assert_eq!(DateStr::try_from_iso_str("2024-01-01").unwrap().days_left_in_year(), 365);
+    assert_eq!(DateStr::try_from_iso_str("2023-12-31").unwrap().days_left_in_year(), 0);
+    assert_eq!(DateStr::try_from_iso_str("2024-07-01").unwrap().days_left_in_year(), 183);
+}
+
+#[test]
+fn days_elapsed_in_year_count() {
+    assert_eq!(DateStr::try_from_iso_str("2023-01-01").unwrap().days_elapsed_in_year(), 0);
+    assert_eq!(DateStr::try_from_iso_str("2023-07-01").unwrap().days_elapsed_in_year(), 181);
+    assert_eq!(DateStr::try_from_iso_str("2024-07-01").unwrap().days_elapsed_in_year(), 182);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_format_matches_try_format() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let formatted: String = some_date.format(DateFormat::from_string("dd/mm/yyyy", None).unwrap());
+    let tried: String = some_date
+        .try_format(DateFormat::from_string("dd/mm/yyyy", None).unwrap())
+        .unwrap();
+    assert_eq!(formatted, tried);
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "Format not recognized")]
+fn deprecated_format_panics_on_invalid_formatter() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    some_date.format(DateFormat {
+        formatter: "YYYY-MM-DD x".to_owned(),
+    });
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn from_env_or_reads_variable() {
+    // Changing the environment of this process races with every other thread reading it, so the
+    // checks run in a child copy of this test binary that gets the variables at spawn time
+    if std::env::var_os("DATES_STR_TEST_FROM_ENV").is_none() {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "tests::from_env_or_reads_variable", "--test-threads=1"])
+            .env("DATES_STR_TEST_FROM_ENV", " 2022/12/31 ")
+            .env("DATES_STR_TEST_FROM_ENV_INVALID", "not a date")
+            .env_remove("DATES_STR_TEST_FROM_ENV_UNSET")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{}", stdout);
+        assert!(stdout.contains("1 passed"), "{}", stdout);
+        return;
+    }
+    assert_eq!(
+        DateStr::from_env_or("DATES_STR_TEST_FROM_ENV", DateStr::EPOCH),
+        DateStr::try_from_iso_str("2022-12-31").unwrap()
+    );
+    assert_eq!(
+        DateStr::from_env_or("DATES_STR_TEST_FROM_ENV_UNSET", DateStr::EPOCH),
+        DateStr::EPOCH
+    );
+    assert_eq!(
+        DateStr::from_env_or("DATES_STR_TEST_FROM_ENV_INVALID", DateStr::EPOCH),
+        DateStr::EPOCH
+    );
+}
+
+#[test]
+fn iso_week_numbers() {
+    assert_eq!(DateStr::try_from_iso_str("2023-01-01").unwrap().iso_week(), 52);
+    assert_eq!(DateStr::try_from_iso_str("2023-01-02").unwrap().iso_week(), 1);
+    assert_eq!(DateStr::try_from_iso_str("2020-12-31").unwrap().iso_week(), 53);
+    assert_eq!(DateStr::try_from_iso_str("2024-12-30").unwrap().iso_week(), 1);
+}
+
+#[test]
+fn iso_week_date_at_year_boundaries() {
+    let week_date = |date: &str| DateStr::try_from_iso_str(date).unwrap().to_iso_week_date();
+    assert_eq!(week_date("2022-12-31"), "2022-W52-6");
+    assert_eq!(week_date("2021-01-01"), "2020-W53-5");
+    assert_eq!(week_date("2024-12-30"), "2025-W01-1");
+    assert_eq!(week_date("2023-06-15"), "2023-W24-4");
+}
+
+#[test]
+fn northern_seasons() {
+    let season = |date: &str| DateStr::try_from_iso_str(date).unwrap().season();
+    assert_eq!(season("2023-01-15"), Season::Winter);
+    assert_eq!(season("2023-03-01"), Season::Spring);
+    assert_eq!(season("2023-07-15"), Season::Summer);
+    assert_eq!(season("2023-11-30"), Season::Autumn);
+    assert_eq!(season("2023-12-01"), Season::Winter);
+}
+
+#[test]
+fn southern_seasons() {
+    let season = |date: &str| DateStr::try_from_iso_str(date).unwrap().southern_season();
+    assert_eq!(season("2023-01-15"), Season::Summer);
+    assert_eq!(season("2023-04-15"), Season::Autumn);
+    assert_eq!(season("2023-07-15"), Season::Winter);
+    assert_eq!(season("2023-10-15"), Season::Spring);
+}
+
+#[test]
+fn format_str_in_one_call() {
+    let some_date: DateStr = DateStr::try_from_iso_str("2022-12-29").unwrap();
+    assert_eq!(some_date.format_str("dd-mm-yyyy", None).unwrap(), "29-12-2022");
+    assert_eq!(some_date.format_str("yyyy/mm/dd", Some('/')).unwrap(), "2022/12/29");
+    assert!(matches!(
+        some_date.format_str("dd-mm", None),
+        Err(DateErrors::FormatDateError(_))
+    ));
+}
+
+#[test]
+fn step_by_days_weekly() {
+    let start = DateStr::try_from_iso_str("2022-12-19").unwrap();
+    let end = DateStr::try_from_iso_str("2023-01-09").unwrap();
+    let weeks: Vec<String> = start.step_by_days(&end, 7).map(|d| d.to_string()).collect();
+    assert_eq!(weeks, ["2022-12-19", "2022-12-26", "2023-01-02", "2023-01-09"]);
+    let before_end = DateStr::try_from_iso_str("2023-01-08").unwrap();
+    assert_eq!(start.step_by_days(&before_end, 7).count(), 3);
+}
+
+#[test]
+fn step_by_days_zero_is_empty() {
+    let start = DateStr::try_from_iso_str("2022-12-19").unwrap();
+    assert_eq!(start.step_by_days(&start, 0).count(), 0);
+    assert_eq!(start.step_by_days(&start, 1).count(), 1);
+    assert_eq!(DateStr::MAX.step_by_days(&DateStr::MAX, 7).count(), 1);
+}
+
+#[test]
+fn sub_days_adjacent() {
+    let date = DateStr::try_from_iso_str("2024-03-01").unwrap();
+    let previous = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    assert_eq!(date.sub_days(&previous), 1);
+    assert_eq!(previous.sub_days(&date), -1);
+    assert_eq!(date.sub_days(&date), 0);
+}
+
+#[test]
+fn sub_days_far_apart() {
+    let date = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    // 25 leap years from 1924 to 2020
+    assert_eq!(date.sub_days(&DateStr::try_from_iso_str("1923-01-01").unwrap()), 36525);
+    let four_centuries = DateStr::try_from_iso_str("2400-01-01").unwrap();
+    assert_eq!(four_centuries.sub_days(&DateStr::try_from_iso_str("2000-01-01").unwrap()), 146097);
+}
+
+#[test]
+fn strict_parser_rejects_year_zero() {
+    assert!(matches!(
+        DateStr::try_from_iso_str_strict("0000-01-01"),
+        Err(DateErrors::InvalidYear(0))
+    ));
+    assert!(DateStr::try_from_iso_str("0000-01-01").is_ok());
+}
+
+#[test]
+fn strict_parser_accepts_other_years() {
+    assert_eq!(
+        DateStr::try_from_iso_str_strict("0001-01-01").unwrap(),
+        DateStr::try_from_iso_str("0001-01-01").unwrap()
+    );
+    assert!(matches!(
+        DateStr::try_from_iso_str_strict("2022-13-01"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+}
+
+#[test]
+fn iso_week_year_at_boundaries() {
+    let week_year = |date: &str| DateStr::try_from_iso_str(date).unwrap().iso_week_year();
+    assert_eq!(week_year("2021-01-01"), 2020);
+    assert_eq!(week_year("2021-01-04"), 2021);
+    assert_eq!(week_year("2022-12-31"), 2022);
+    assert_eq!(week_year("2024-12-30"), 2025);
+}
+
+#[test]
+fn age_on_birthday() {
+    let birth = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    assert_eq!(birth.age_on(&DateStr::try_from_iso_str("2022-06-14").unwrap()), 21);
+    assert_eq!(birth.age_on(&DateStr::try_from_iso_str("2022-06-15").unwrap()), 22);
+    assert_eq!(birth.age_on(&birth), 0);
+}
+
+#[test]
+fn age_on_before_birth() {
+    let birth = DateStr::try_from_iso_str("2000-06-15").unwrap();
+    assert_eq!(birth.age_on(&DateStr::try_from_iso_str("1990-06-15").unwrap()), 0);
+}
+
+#[test]
+fn date_stays_copy() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<DateStr>();
+    assert_eq!(core::mem::size_of::<DateStr>(), 16);
+}
+
+#[test]
+fn packed_round_trip() {
+    for date in ["0000-01-01", "1970-01-01", "2024-02-29", "9999-12-31"] {
+        let date = DateStr::try_from_iso_str(date).unwrap();
+        assert_eq!(DateStr::from_packed(date.to_packed()).unwrap(), date);
+    }
+    let earlier = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let later = DateStr::try_from_iso_str("2023-01-01").unwrap();
+    assert!(earlier.to_packed() < later.to_packed());
+}
+
+#[test]
+fn invalid_packed_dates() {
+    assert!(matches!(
+        DateStr::from_packed(2022 << 16 | 13 << 8 | 1),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+    assert!(matches!(
+        DateStr::from_packed(2023 << 16 | 2 << 8 | 29),
+        Err(DateErrors::InvalidDay { day: 29 })
+    ));
+}
+
+#[test]
+#[should_panic]
+fn packed_year_too_big() {
+    DateStr::MAX.to_packed();
+}
+
+#[test]
+fn parse_with_formats_falls_through() {
+    let formats = ["yyyy-mm-dd", "dd/mm/yyyy"];
+    let date = DateStr::parse_with_formats("31/12/2022", &formats).unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(DateStr::parse_with_formats("2022-12-31", &formats).unwrap(), date);
+    assert!(DateStr::parse_with_formats("31.12.2022", &formats).is_err());
+    assert!(DateStr::parse_with_formats("2022-12-31", &[]).is_err());
+}
+
+#[test]
+fn parse_with_formats_names_and_compact() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(DateStr::parse_with_formats("20221231", &["yyyymmdd"]).unwrap(), date);
+    assert_eq!(
+        DateStr::parse_with_formats("sat, 31st DECEMBER 2022", &["ddd, Do mmmm yyyy"]).unwrap(),
+        date
+    );
+    assert_eq!(DateStr::parse_with_formats("Dec 31 2022", &["mmm d yyyy"]).unwrap(), date);
+    // 2022-12-31 is a Saturday, not a Sunday
+    assert!(matches!(
+        DateStr::parse_with_formats("Sunday 2022-12-31", &["dddd yyyy-mm-dd"]),
+        Err(DateErrors::InvalidComponent { component: "weekday", .. })
+    ));
+    assert!(DateStr::parse_with_formats("2022-12-31 trailing", &["yyyy-mm-dd"]).is_err());
+    assert!(DateStr::parse_with_formats("2022-2-31", &["yyyy-mm-dd"]).is_err());
+}
+
+#[test]
+fn validate_all_reports_every_part() {
+    let problems = DateStr::validate_all(2022, 13, 32).unwrap_err();
+    assert_eq!(problems.len(), 2);
+    assert!(matches!(problems[0], DateErrors::InvalidMonth { month: 13 }));
+    assert!(matches!(problems[1], DateErrors::InvalidDay { day: 32 }));
+}
+
+#[test]
+fn validate_all_single_problem() {
+    assert!(DateStr::validate_all(2022, 12, 31).is_ok());
+    let problems = DateStr::validate_all(2023, 2, 29).unwrap_err();
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0], DateErrors::InvalidDay { day: 29 }));
+    let problems = DateStr::validate_all(2023, 0, 15).unwrap_err();
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0], DateErrors::InvalidMonth { month: 0 }));
+}
+
+#[test]
+fn date_diff_display_plural() {
+    let start = DateStr::try_from_iso_str("2020-01-15").unwrap();
+    let end = DateStr::try_from_iso_str("2022-03-10").unwrap();
+    assert_eq!(start.diff(&end).to_string(), "2 years, 1 month, 23 days");
+}
+
+#[test]
+fn date_diff_display_singular_and_zero() {
+    let diff = |years, months, days| DateDiff {
+        years,
+        months,
+        days,
+    };
+    assert_eq!(diff(1, 1, 1).to_string(), "1 year, 1 month, 1 day");
+    assert_eq!(diff(0, 3, 0).to_string(), "3 months");
+    assert_eq!(diff(2, 0, 1).to_string(), "2 years, 1 day");
+    assert_eq!(diff(0, 0, 0).to_string(), "0 days");
+}
+
+#[test]
+fn fridays_in_a_long_month() {
+    // December 2022 starts on a Thursday, so it has five Fridays
+    let december = DateStr::try_from_iso_str("2022-12-15").unwrap();
+    let fridays = december.weekdays_in_month(Weekday::Friday);
+    assert_eq!(fridays.len(), 5);
+    assert_eq!(fridays[0].to_string(), "2022-12-02");
+    assert_eq!(fridays[4].to_string(), "2022-12-30");
+    assert!(fridays.iter().all(|day| day.weekday() == Weekday::Friday));
+    // January 2023 starts on a Sunday, so it has four
+    let january = DateStr::try_from_iso_str("2023-01-15").unwrap();
+    assert_eq!(january.weekdays_in_month(Weekday::Friday).len(), 4);
+}
+
+#[test]
+fn from_compact_eight_digits() {
+    let date = DateStr::from_compact("20221231").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(DateStr::from_compact(&date.format_compact()).unwrap(), date);
+}
+
+#[test]
+fn from_compact_rejects_malformed() {
+    for input in ["2022123", "202212311", "2022-12-31", "2022I231", "+2022123", ""] {
+        assert!(
+            matches!(DateStr::from_compact(input), Err(DateErrors::InvalidParsing(_))),
+            "{:?}",
+            input
+        );
+    }
+    assert!(matches!(
+        DateStr::from_compact("20221331"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+}
+
+#[test]
+fn earliest_and_latest_dates() {
+    let dates: Vec<DateStr> = ["2022-12-31", "2023-01-01", "1999-02-28", "2022-06-15"]
+        .iter()
+        .map(|date| DateStr::try_from_iso_str(date).unwrap())
+        .collect();
+    assert_eq!(DateStr::earliest(&dates).unwrap().to_string(), "1999-02-28");
+    assert_eq!(DateStr::latest(&dates).unwrap().to_string(), "2023-01-01");
+}
+
+#[test]
+fn earliest_and_latest_of_nothing() {
+    assert_eq!(DateStr::earliest(&[]), None);
+    assert_eq!(DateStr::latest(&[]), None);
+}
+
+#[test]
+fn date_as_tuple() {
+    let (year, month, day) = DateStr::try_from_iso_str("2024-02-29").unwrap().as_tuple();
+    assert_eq!(year, 2024);
+    assert_eq!(month, 2);
+    assert_eq!(day, 29);
+    assert!(matches!(DateStr::MIN.as_tuple(), (0, 1, 1)));
+}
+
+#[test]
+fn date_from_tuple() {
+    let date = DateStr::try_from((2022, 12, 31)).unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(DateStr::try_from(date.as_tuple()).unwrap(), date);
+}
+
+#[test]
+fn invalid_date_from_tuple() {
+    assert!(matches!(
+        DateStr::try_from((2023, 2, 29)),
+        Err(DateErrors::InvalidDay { day: 29 })
+    ));
+    assert!(matches!(
+        DateStr::try_from((2023, 0, 1)),
+        Err(DateErrors::InvalidMonth { month: 0 })
+    ));
+}
+
+#[test]
+fn from_rfc3339_valid_timestamps() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(DateStr::from_rfc3339("2022-12-31T00:00:00Z").unwrap(), date);
+    assert_eq!(DateStr::from_rfc3339("2022-12-31t23:59:60z").unwrap(), date);
+    assert_eq!(DateStr::from_rfc3339("2022-12-31T23:59:59.123-05:30").unwrap(), date);
+    assert_eq!(DateStr::from_rfc3339(&date.to_rfc3339()).unwrap(), date);
+}
+
+#[test]
+fn from_rfc3339_malformed() {
+    for input in [
+        "2022-12-31",
+        "2022-12-31 00:00:00Z",
+        "2022-12-3100:00:00Z",
+        "2022-12-31T00:00:00",
+        "2022-12-31T24:00:00Z",
+        "2022-12-31T00:00Z",
+        "2022-12-31T00:00:00.Z",
+        "2022-12-31T00:00:00+0200",
+        "2022-1-31T00:00:00Z",
+        "2022-12-31T00:00:00Zé",
+        "2022-12-31Tééééééééé",
+        "",
+    ] {
+        assert!(
+            matches!(DateStr::from_rfc3339(input), Err(DateErrors::InvalidParsing(_))),
+            "{:?}",
+            input
+        );
+    }
+    assert!(matches!(
+        DateStr::from_rfc3339("2022-02-30T00:00:00Z"),
+        Err(DateErrors::InvalidDay { day: 30 })
+    ));
+}
+
+#[test]
+fn date_range_contains_endpoints() {
+    let range = DateRange {
+        start: DateStr::try_from_iso_str("2022-12-01").unwrap(),
+        end: DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    };
+    assert!(range.contains(&range.start));
+    assert!(range.contains(&range.end));
+    assert!(range.contains(&DateStr::try_from_iso_str("2022-12-15").unwrap()));
+}
+
+#[test]
+fn date_range_excludes_outside() {
+    let range = DateRange {
+        start: DateStr::try_from_iso_str("2022-12-01").unwrap(),
+        end: DateStr::try_from_iso_str("2022-12-31").unwrap(),
+    };
+    assert!(!range.contains(&DateStr::try_from_iso_str("2022-11-30").unwrap()));
+    assert!(!range.contains(&DateStr::try_from_iso_str("2023-01-01").unwrap()));
+}
+
+fn date_range(start: &str, end: &str) -> DateRange {
+    DateRange {
+        start: DateStr::try_from_iso_str(start).unwrap(),
+        end: DateStr::try_from_iso_str(end).unwrap(),
+    }
+}
+
+#[test]
+fn date_ranges_overlapping() {
+    let december = date_range("2022-12-01", "2022-12-31");
+    let holidays = date_range("2022-12-24", "2023-01-06");
+    assert!(december.overlaps(&holidays));
+    assert!(holidays.overlaps(&december));
+    assert_eq!(
+        december.intersection(&holidays),
+        Some(date_range("2022-12-24", "2022-12-31"))
+    );
+    let last_day = date_range("2022-12-31", "2023-01-31");
+    assert_eq!(
+        december.intersection(&last_day),
+        Some(date_range("2022-12-31", "2022-12-31"))
+    );
+}
+
+#[test]
+fn date_ranges_disjoint() {
+    let december = date_range("2022-12-01", "2022-12-31");
+    let january = date_range("2023-01-01", "2023-01-31");
+    assert!(!december.overlaps(&january));
+    assert_eq!(december.intersection(&january), None);
+    let empty = date_range("2022-12-20", "2022-12-10");
+    assert!(!december.overlaps(&empty));
+}
+
+#[test]
+fn date_ranges_nested() {
+    let year = date_range("2022-01-01", "2022-12-31");
+    let march = date_range("2022-03-01", "2022-03-31");
+    assert!(year.overlaps(&march));
+    assert_eq!(year.intersection(&march), Some(march));
+    assert_eq!(march.intersection(&year), Some(march));
+}
+
+#[test]
+fn date_range_len_days() {
+    assert_eq!(date_range("2022-12-31", "2022-12-31").len_days(), 1);
+    assert_eq!(date_range("2023-02-01", "2023-02-28").len_days(), 28);
+    assert_eq!(date_range("2024-02-01", "2024-03-01").len_days(), 30);
+    assert_eq!(date_range("2023-02-01", "2023-03-01").len_days(), 29);
+    assert_eq!(date_range("2022-12-31", "2022-12-30").len_days(), 0);
+    let range = date_range("2024-02-27", "2024-03-02");
+    assert_eq!(range.len_days(), (&range).into_iter().count() as u64);
+}
+
+#[test]
+fn normalize_month_overflow() {
+    assert_eq!(DateStr::normalize(2022, 13, 1).to_string(), "2023-01-01");
+    assert_eq!(DateStr::normalize(2022, 25, 15).to_string(), "2024-01-15");
+    assert_eq!(DateStr::normalize(2022, 0, 1).to_string(), "2021-12-01");
+    assert_eq!(DateStr::normalize(2022, 12, 31).to_string(), "2022-12-31");
+}
+
+#[test]
+fn normalize_day_overflow() {
+    assert_eq!(DateStr::normalize(2023, 1, 32).to_string(), "2023-02-01");
+    assert_eq!(DateStr::normalize(2023, 2, 29).to_string(), "2023-03-01");
+    assert_eq!(DateStr::normalize(2022, 12, 255).to_string(), "2023-08-12");
+    assert_eq!(DateStr::normalize(2023, 1, 0).to_string(), "2022-12-31");
+}
+
+#[test]
+#[should_panic]
+fn normalize_before_year_zero() {
+    DateStr::normalize(0, 1, 0);
+}
+
+#[test]
+fn signed_date_julian_day_round_trip() {
+    let ides = SignedDate::new(-44, Month::new(3).unwrap(), Day::new(15).unwrap()).unwrap();
+    assert_eq!(ides.year(), -44);
+    assert_eq!(ides.to_julian_day(), 1705063);
+    assert_eq!(SignedDate::from_julian_day(1705063), ides);
+    assert_eq!(SignedDate::from_julian_day(0).to_string(), "-4713-11-24");
+    assert_eq!(SignedDate::from_julian_day(-1).to_string(), "-4713-11-23");
+}
+
+#[test]
+fn signed_date_leap_years() {
+    // -44 is divisible by 4, -100 is not leap but -400 is
+    assert!(SignedDate::new(-44, Month::new(2).unwrap(), Day::new(29).unwrap()).is_ok());
+    assert!(SignedDate::new(-100, Month::new(2).unwrap(), Day::new(29).unwrap()).is_err());
+    assert!(SignedDate::new(-400, Month::new(2).unwrap(), Day::new(29).unwrap()).is_ok());
+    assert!(SignedDate::new(-45, Month::new(2).unwrap(), Day::new(29).unwrap()).is_err());
+}
+
+#[test]
+fn signed_date_conversions() {
+    let date = DateStr::try_from_iso_str("2000-01-01").unwrap();
+    let signed = SignedDate::try_from(date).unwrap();
+    assert_eq!(signed.to_julian_day(), date.to_julian_day());
+    assert_eq!(DateStr::try_from(signed).unwrap(), date);
+    let bce = SignedDate::from_julian_day(DateStr::MIN.to_julian_day() - 1);
+    assert_eq!(bce.to_string(), "-0001-12-31");
+    assert!(matches!(DateStr::try_from(bce), Err(DateErrors::NegativeYear(-1))));
+    assert!(matches!(SignedDate::try_from(DateStr::MAX), Err(DateErrors::InvalidYear(u64::MAX))));
+}
+
+#[test]
+fn parse_prefix_returns_rest() {
+    let (date, rest) = DateStr::parse_prefix("2022-12-31 extra").unwrap();
+    assert_eq!(date, DateStr::try_from_iso_str("2022-12-31").unwrap());
+    assert_eq!(rest, " extra");
+    let (date, rest) = DateStr::parse_prefix("2022-1-5").unwrap();
+    assert_eq!(date.to_string(), "2022-01-05");
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn parse_prefix_errors() {
+    assert!(matches!(DateStr::parse_prefix("extra 2022-12-31"), Err(DateErrors::InvalidParsing(_))));
+    assert!(matches!(DateStr::parse_prefix("2022-12"), Err(DateErrors::InvalidParsing(_))));
+    assert!(matches!(DateStr::parse_prefix("2022-12-"), Err(DateErrors::InvalidParsing(_))));
+    assert!(matches!(
+        DateStr::parse_prefix("2022-13-01 extra"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+}
+
+#[test]
+fn equality_ignores_source_format() {
+    let iso = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    let parsed = [
+        DateStr::parse_auto("2022/12/31").unwrap(),
+        DateStr::parse_auto("2022.12.31").unwrap(),
+        DateStr::parse_auto("2022 12 31").unwrap(),
+        DateStr::from_us_str("12/31/2022").unwrap(),
+        DateStr::from_long_str("31 December 2022").unwrap(),
+        DateStr::from_compact("20221231").unwrap(),
+        DateStr::from_rfc3339("2022-12-31T10:00:00+02:00").unwrap(),
+        DateStr::parse_with_formats("31.12.2022", &["dd.mm.yyyy"]).unwrap(),
+    ];
+    for date in parsed {
+        assert_eq!(date, iso);
+        assert!(date.is_same(&iso));
+    }
+}
+
+#[test]
+fn equality_ignores_padding() {
+    let padded = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let unpadded = DateStr::try_from_iso_str("2022-1-5").unwrap();
+    assert_eq!(padded, unpadded);
+    assert_eq!(padded.to_string(), unpadded.to_string());
+    assert_ne!(padded, DateStr::parse_auto("2022/01/06").unwrap());
+}
+
+#[test]
+fn display_pads_year_to_four_digits() {
+    let early = DateStr::try_from_iso_str("99-01-01").unwrap();
+    assert_eq!(early.to_string(), "0099-01-01");
+    assert_eq!(DateStr::try_from_iso_str("0099-01-01").unwrap(), early);
+    assert_eq!(DateStr::try_from_iso_str("2022-01-01").unwrap().to_string(), "2022-01-01");
+    assert_eq!(
+        DateStr::try_from_iso_str("12345-01-01").unwrap().to_string(),
+        "12345-01-01"
+    );
+}
+
+#[test]
+fn compact_and_formatted_year_padding() {
+    let early = DateStr::try_from_iso_str("0099-01-01").unwrap();
+    assert_eq!(early.format_compact(), "00990101");
+    assert_eq!(DateStr::from_compact(&early.format_compact()).unwrap(), early);
+    assert_eq!(early.format_str("yyyy/mm/dd", None).unwrap(), "0099/01/01");
+    assert!(early == "0099-01-01");
+}
+
+#[test]
+fn parse_flexible_shapes() {
+    let expected = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(DateStr::parse_flexible("2022-12-31").unwrap(), expected);
+    assert_eq!(DateStr::parse_flexible("31 dec 2022").unwrap(), expected);
+    assert_eq!(DateStr::parse_flexible("31 December 2022").unwrap(), expected);
+    assert_eq!(DateStr::parse_flexible("Dec 31, 2022").unwrap(), expected);
+    assert_eq!(DateStr::parse_flexible("DECEMBER 31, 2022").unwrap(), expected);
+}
+
+#[test]
+fn parse_flexible_rejects_unknown_shapes() {
+    assert!(matches!(
+        DateStr::parse_flexible("31/12/2022"),
+        Err(DateErrors::InvalidParsing(_))
+    ));
+    assert!(DateStr::parse_flexible("Feb 30, 2022").is_err());
+    assert!(DateStr::parse_flexible("Dec 31 2022").is_err());
+}
+
+#[test]
+fn leap_year_neighbours_skip_the_year_itself() {
+    assert_eq!(Year::new(2020).next_leap(), Year::new(2024));
+    assert_eq!(Year::new(2020).previous_leap(), Year::new(2016));
+    assert_eq!(Year::new(2000).next_leap(), Year::new(2004));
+    assert_eq!(Year::new(2000).previous_leap(), Year::new(1996));
+    assert_eq!(Year::new(1899).next_leap(), Year::new(1904));
+    assert_eq!(Year::new(1904).previous_leap(), Year::new(1896));
+    assert_eq!(Year::new(5).previous_leap(), Year::new(4));
+    assert_eq!(Year::new(3).previous_leap(), Year::new(0));
+}
+
+#[test]
+#[should_panic]
+fn previous_leap_before_year_zero_panics() {
+    Year::new(0).previous_leap();
+}
+
+#[test]
+fn last_of_month_respects_leap_years() {
+    let leap_day = DateStr::try_from_iso_str("2024-02-29").unwrap();
+    assert!(leap_day.is_last_of_month());
+    assert!(!DateStr::try_from_iso_str("2024-02-28").unwrap().is_last_of_month());
+    let common = DateStr::try_from_iso_str("2023-02-28").unwrap();
+    assert!(common.is_last_of_month());
+    assert!(!common.is_first_of_month());
+    let next = common + Days(1);
+    assert_eq!(next.to_string(), "2023-03-01");
+    assert!(next.is_first_of_month());
+    assert!(!next.is_last_of_month());
+    assert!(DateStr::try_from_iso_str("2022-04-30").unwrap().is_last_of_month());
+}
+
+#[test]
+fn wrong_part_count_for_too_few_parts() {
+    let error: DateErrors = DateStr::try_from_iso_str("2022-12").unwrap_err();
+    assert!(matches!(error, DateErrors::WrongPartCount { expected: 3, found: 2 }));
+    assert_eq!(error.to_string(), "expected 3 date parts, found 2");
+    assert!(matches!(
+        DateStr::from_us_str("12/31"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 2 })
+    ));
+    assert!(matches!(
+        DateStr::from_long_str("December 2022"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 2 })
+    ));
+}
+
+#[test]
+fn wrong_part_count_for_too_many_parts() {
+    assert!(matches!(
+        DateStr::try_from_iso_str("2022-12-31-05"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 4 })
+    ));
+    assert!(matches!(
+        DateStr::from_us_str("12/31/2022/1"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 4 })
+    ));
+    assert!(matches!(
+        DateStr::from_long_str("Saturday 31 December 2022"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 4 })
+    ));
+}
+
+#[test]
+fn from_ordinal_respects_leap_years() {
+    assert_eq!(DateStr::from_ordinal(2024, 60).unwrap().to_string(), "2024-02-29");
+    assert_eq!(DateStr::from_ordinal(2023, 60).unwrap().to_string(), "2023-03-01");
+    assert_eq!(DateStr::from_ordinal(2024, 366).unwrap().to_string(), "2024-12-31");
+    assert_eq!(DateStr::from_ordinal(2023, 1).unwrap().to_string(), "2023-01-01");
+    for ordinal in [1, 59, 60, 200, 365] {
+        assert_eq!(DateStr::from_ordinal(2023, ordinal).unwrap().day_of_year(), ordinal);
+    }
+}
+
+#[test]
+fn from_ordinal_rejects_days_outside_the_year() {
+    assert!(matches!(
+        DateStr::from_ordinal(2023, 366),
+        Err(DateErrors::InvalidComponent { component: "ordinal", .. })
+    ));
+    assert!(DateStr::from_ordinal(2024, 367).is_err());
+    assert!(DateStr::from_ordinal(2024, 0).is_err());
+}
+
+#[test]
+fn humanize_same_day_and_neighbours() {
+    let reference = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!(reference.humanize_from(&reference), "today");
+    assert_eq!((reference + Days(1)).humanize_from(&reference), "tomorrow");
+    assert_eq!((reference - Days(1)).humanize_from(&reference), "yesterday");
+}
+
+#[test]
+fn humanize_future_and_past() {
+    let reference = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    assert_eq!((reference + Days(3)).humanize_from(&reference), "in 3 days");
+    assert_eq!((reference + Days(7)).humanize_from(&reference), "in 1 week");
+    assert_eq!((reference + Days(20)).humanize_from(&reference), "in 2 weeks");
+    let last_month = DateStr::try_from_iso_str("2022-11-30").unwrap();
+    assert_eq!(last_month.humanize_from(&reference), "1 month ago");
+    let months_ago = DateStr::try_from_iso_str("2022-10-15").unwrap();
+    assert_eq!(months_ago.humanize_from(&reference), "2 months ago");
+    let years_ago = DateStr::try_from_iso_str("2020-06-01").unwrap();
+    assert_eq!(years_ago.humanize_from(&reference), "2 years ago");
+}
+
+#[test]
+fn write_formatted_matches_try_format() {
+    let date = DateStr::try_from_iso_str("2022-01-02").unwrap();
+    for pattern in ["yyyy-mm-dd", "dddd, Do [of] mmmm yyyy", "d/m/yyyy", "mmm dd yyyy (ddd)"] {
+        let fmt = DateFormat::from_string(pattern, None).unwrap();
+        let mut buffer = String::new();
+        date.write_formatted(&mut buffer, &fmt).unwrap();
+        assert_eq!(buffer, date.try_format(fmt).unwrap(), "{}", pattern);
+    }
+}
+
+#[test]
+fn write_formatted_fails_for_tampered_formatter() {
+    let date = DateStr::try_from_iso_str("2022-01-02").unwrap();
+    let mut fmt = DateFormat::from_string("yyyy-mm-dd", None).unwrap();
+    fmt.formatter = "YYYY-[MM-DD".to_owned();
+    let mut buffer = String::new();
+    assert!(date.write_formatted(&mut buffer, &fmt).is_err());
+}
+
+#[test]
+fn format_adjacent_tokens() {
+    let date = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let cases = [
+        ("YYYYMMDD", "20220105"),
+        ("DDMMYYYY", "05012022"),
+        ("YYYYMD", "202215"),
+        ("MMMMDYYYY", "January52022"),
+        ("MMMDDYYYY", "Jan052022"),
+        ("DDDDDYYYYMM", "Wednesday5202201"),
+    ];
+    for (pattern, expected) in cases {
+        let fmt = DateFormat::from_string(pattern, None).unwrap();
+        assert_eq!(date.try_format(fmt).unwrap(), expected, "{}", pattern);
+    }
+}
+
+#[test]
+fn format_tokens_mixed_with_literals() {
+    let date = DateStr::try_from_iso_str("2022-01-05").unwrap();
+    let cases = [
+        ("YYYY.MM.DD", "2022.01.05"),
+        ("(DD) MM/YYYY!", "(05) 01/2022!"),
+        ("[Day] D, [Month] M, [Year] YYYY", "Day 5, Month 1, Year 2022"),
+        ("YYYY – MM – DD", "2022 – 01 – 05"),
+    ];
+    for (pattern, expected) in cases {
+        let fmt = DateFormat::from_string(pattern, None).unwrap();
+        assert_eq!(date.try_format(fmt).unwrap(), expected, "{}", pattern);
+    }
+}
+
+#[test]
+fn from_iso_fast_matches_try_from_iso_str() {
+    let inputs = [
+        "2022-12-31",
+        "2022-3-5",
+        "0099-01-01",
+        "2024-02-29",
+        "2023-02-29",
+        "2022-13-01",
+        "2022-00-10",
+        "2022-12",
+        "2022-12-31-01",
+        "",
+        "2022--31",
+        "2022-xx-31",
+        "2022-12-3x",
+        "2022-13-3x",
+        "2022-00-3x",
+        "-1x-31",
+        "2022-1x-",
+        "2022-256-3x",
+        "2022-256-01",
+        "18446744073709551615-12-31",
+        "18446744073709551616-12-31",
+    ];
+    for input in inputs {
+        match (DateStr::from_iso_fast(input), DateStr::try_from_iso_str(input)) {
+            (Ok(fast), Ok(lenient)) => assert_eq!(fast, lenient, "{}", input),
+            (Err(fast), Err(lenient)) => {
+                assert_eq!(fast.to_string(), lenient.to_string(), "{}", input)
+            }
+            (fast, lenient) => panic!("{}: {:?} and {:?} differ", input, fast, lenient),
+        }
+    }
+}
+
+#[test]
+fn from_iso_fast_rejects_signs() {
+    let signed = [("+2022-12-31", "year"), ("2022-+12-31", "month"), ("2022-12-+31", "day")];
+    for (input, expected) in signed {
+        assert_eq!(
+            DateStr::try_from_iso_str(input).unwrap().to_string(),
+            "2022-12-31",
+            "{}",
+            input
+        );
+        assert!(
+            matches!(
+                DateStr::from_iso_fast(input),
+                Err(DateErrors::InvalidComponent { component, .. }) if component == expected
+            ),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn try_from_iso_str_results_are_unchanged() {
+    let valid = [
+        ("2022-12-31", (2022, 12, 31)),
+        ("2022-3-5", (2022, 3, 5)),
+        ("0-01-01", (0, 1, 1)),
+        ("2024-002-029", (2024, 2, 29)),
+    ];
+    for (input, expected) in valid {
+        assert_eq!(DateStr::try_from_iso_str(input).unwrap().as_tuple(), expected, "{}", input);
+    }
+    let errors = [
+        ("2022-12-", "failed to parse day in '2022-12-'"),
+        ("-12-31", "failed to parse year in '-12-31'"),
+        ("2022-12-31-", "expected 3 date parts, found 4"),
+        ("2022-13-31", "Invalid Month: provided 13"),
+        ("2023-02-29", "Invalid Day: provided 29"),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(DateStr::try_from_iso_str(input).unwrap_err().to_string(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn week_of_month_sunday_first() {
+    // January 2023 starts on a Sunday
+    let weeks = [(1, 1, 1), (2, 2, 1), (7, 2, 1), (8, 2, 2), (9, 3, 2), (31, 6, 5)];
+    for (day, monday_first, sunday_first) in weeks {
+        let date: DateStr = DateStr::try_from_iso_str("2023-01-01")
+            .unwrap()
+            .with_day(day)
+            .unwrap();
+        assert_eq!(date.week_of_month_starting(WeekStart::Monday), monday_first, "day {}", day);
+        assert_eq!(date.week_of_month_starting(WeekStart::Sunday), sunday_first, "day {}", day);
+        assert_eq!(date.week_of_month(), monday_first, "day {}", day);
+    }
+}
+
+#[test]
+fn week_of_month_sunday_first_in_month_starting_monday() {
+    // May 2023 starts on a Monday, so Sunday the 7th starts the second week
+    let date: DateStr = DateStr::try_from_iso_str("2023-05-06").unwrap();
+    assert_eq!(date.week_of_month_starting(WeekStart::Sunday), 1);
+    assert_eq!((date + Days(1)).week_of_month_starting(WeekStart::Sunday), 2);
+    assert_eq!((date + Days(1)).week_of_month_starting(WeekStart::Monday), 1);
+    assert_eq!(WeekStart::default(), WeekStart::Monday);
+}
+
+#[test]
+#[should_panic]
+fn julian_day_of_max_date_panics() {
+    DateStr::MAX.to_julian_day();
+}
+
+#[test]
+#[should_panic]
+fn julian_day_of_max_signed_date_panics() {
+    SignedDate::new(i64::MAX, Month(12), Day(31)).unwrap().to_julian_day();
+}
+
+#[test]
+fn julian_day_of_large_year() {
+    let date = DateStr::new(Year::new(10_000_000_000_000_000), Month(1), Day(1)).unwrap();
+    assert_eq!(DateStr::from_julian_day(date.to_julian_day()), date);
+}
+
+#[test]
+#[should_panic]
+fn days_between_min_and_max_panics() {
+    DateStr::MIN.days_between(&DateStr::MAX);
+}
+
+#[test]
+#[should_panic]
+fn sub_days_max_and_min_panics() {
+    DateStr::MAX.sub_days(&DateStr::MIN);
+}
+
+#[test]
+fn days_between_near_the_bounds() {
+    assert_eq!(DateStr::MIN.days_between(&(DateStr::MIN + Days(10))), 10);
+    assert_eq!((DateStr::MAX - Days(10)).days_between(&DateStr::MAX), 10);
+    assert_eq!(DateStr::MAX.sub_days(&(DateStr::MAX - Days(3))), 3);
+}
+
+#[test]
+fn humanize_from_at_the_bounds() {
+    assert_eq!(
+        DateStr::MAX.humanize_from(&DateStr::MIN),
+        "in 18458999787237975085 years"
+    );
+    assert_eq!(
+        DateStr::MIN.humanize_from(&DateStr::MAX),
+        "18458999787237975085 years ago"
+    );
+}
+
+#[test]
+fn years_since_large_years() {
+    let far = DateStr::new(Year::new(9_000_000_000_000_000_000), Month(6), Day(1)).unwrap();
+    assert_eq!(far.years_since(&DateStr::EPOCH), 8_999_999_999_999_998_030);
+    assert_eq!(DateStr::EPOCH.years_since(&far), -8_999_999_999_999_998_030);
+    let farther = DateStr::new(Year::new(10_000_000_000_000_000_000), Month(1), Day(1)).unwrap();
+    assert_eq!(DateStr::EPOCH.age_on(&farther), 9_999_999_999_999_998_030);
+}
+
+#[test]
+#[should_panic]
+fn years_since_beyond_i64_panics() {
+    let farther = DateStr::new(Year::new(10_000_000_000_000_000_000), Month(1), Day(1)).unwrap();
+    farther.years_since(&DateStr::EPOCH);
+}
+
+#[test]
+fn day_sub_borrows_months() {
+    assert_eq!(Day(10) - Day(4), (Day(6), Month(0)));
+    assert_eq!(Day(31) - Day(1), (Day(30), Month(0)));
+    assert_eq!(Day(5) - Day(5), (Day(30), Month(1)));
+    assert_eq!(Day(1) - Day(5), (Day(26), Month(1)));
+    assert_eq!(Day(1) - Day(31), (Day(30), Month(2)));
+    let (day, months) = Day(1) - Day(5);
+    assert_eq!(day + Day(5), (Day(1), months));
+}
+
+#[test]
+fn month_sub_borrows_years() {
+    assert_eq!(Month(12) - Month(1), (Month(11), Year::new(0)));
+    assert_eq!(Month(3) - Month(3), (Month(12), Year::new(1)));
+    assert_eq!(Month(1) - Month(12), (Month(1), Year::new(1)));
+    assert_eq!(Month(2) - Month(0), (Month(2), Year::new(0)));
+    let (month, years) = Month(1) - Month(12);
+    assert_eq!(month + Month(12), (Month(1), years));
+}
+
+#[test]
+fn long_display_of_componentwise_difference() {
+    let date = DateStr::try_from_iso_str("2022-03-05").unwrap();
+    let difference = date - date;
+    assert!((1..=12).contains(&difference.month.0));
+    assert!((1..=30).contains(&difference.day.0));
+    assert_eq!(format!("{:#}", difference), "30 November 0");
+    let earlier = DateStr::try_from_iso_str("2021-01-01").unwrap();
+    assert_eq!(format!("{:#}", date - earlier), "4 February 1");
+}
+
+#[test]
+fn long_display_never_indexes_out_of_the_names() {
+    let date = DateStr {
+        year: Year::new(0),
+        month: Month(0),
+        day: Day(1),
+    };
+    assert_eq!(format!("{:#}", date), "1 0 0");
+}
+
+#[test]
+fn from_string_ignores_the_separator() {
+    let date = DateStr::try_from_iso_str("2022-12-31").unwrap();
+    for separator in [None, Some('-'), Some('/'), Some('x')] {
+        let fmt = DateFormat::from_string("dd.mm.yyyy", separator).unwrap();
+        assert_eq!(fmt.formatter, "DD.MM.YYYY");
+        assert_eq!(date.try_format(fmt).unwrap(), "31.12.2022");
+    }
+}
+
+#[test]
+fn format_localized_keeps_weekdays_in_english() {
+    let date = DateStr::try_from_iso_str("2022-03-05").unwrap();
+    let fmt = DateFormat::from_string("dddd Do mmmm yyyy", None).unwrap();
+    assert_eq!(
+        date.format_localized(&fmt, Locale::Spanish).unwrap(),
+        "Saturday 5th marzo 2022"
+    );
+}
+
+#[test]
+fn format_localized_fails_for_tampered_formatter() {
+    let date = DateStr::try_from_iso_str("2022-03-05").unwrap();
+    let mut fmt = DateFormat::from_string("yyyy-mm-dd", None).unwrap();
+    fmt.formatter = "YYYY-[MM-DD".to_owned();
+    assert!(matches!(
+        date.format_localized(&fmt, Locale::Spanish),
+        Err(DateErrors::FormatDateError(_))
+    ));
+}
+
+#[test]
+fn iso_week_date_pads_the_year() {
+    let date = DateStr::try_from_iso_str("0099-06-01").unwrap();
+    assert_eq!(date.to_iso_week_date(), "0099-W23-1");
+    assert_eq!(date.to_string(), "0099-06-01");
+    let date = DateStr::try_from_iso_str("12345-06-01").unwrap();
+    assert!(date.to_iso_week_date().starts_with("12345-W"));
+}
+
+#[test]
+fn parse_flexible_keeps_iso_errors() {
+    assert!(matches!(
+        DateStr::parse_flexible("2022-02-30"),
+        Err(DateErrors::InvalidDay { day: 30 })
+    ));
+    assert!(matches!(
+        DateStr::parse_flexible("2022-13-01"),
+        Err(DateErrors::InvalidMonth { month: 13 })
+    ));
+    assert!(matches!(
+        DateStr::parse_flexible("2022-12"),
+        Err(DateErrors::WrongPartCount { expected: 3, found: 2 })
+    ));
+    assert!(matches!(
+        DateStr::parse_flexible(""),
+        Err(DateErrors::InvalidParsing(_))
+    ));
+}
+
+#[test]
+#[should_panic]
+fn business_days_between_min_and_max_panics() {
+    DateStr::MIN.business_days_between(&DateStr::MAX);
+}
+
+#[test]
+#[should_panic]
+fn business_days_between_max_and_min_panics() {
+    DateStr::MAX.business_days_between(&DateStr::MIN);
+}
+
+#[test]
+fn business_days_between_near_the_bounds() {
+    // 0000-01-01 is a Saturday
+    assert_eq!(DateStr::MIN.business_days_between(&(DateStr::MIN + Days(9))), 5);
+    assert_eq!((DateStr::MAX - Days(7)).business_days_between(&DateStr::MAX), 5);
+}
+
+#[test]
+#[should_panic]
+fn len_days_of_the_whole_calendar_panics() {
+    DateRange {
+        start: DateStr::MIN,
+        end: DateStr::MAX,
+    }
+    .len_days();
+}
+
+#[test]
+fn len_days_near_the_bounds() {
+    let near = |start: DateStr, end: DateStr| DateRange { start, end }.len_days();
+    assert_eq!(near(DateStr::MIN, DateStr::MIN + Days(9)), 10);
+    assert_eq!(near(DateStr::MAX - Days(9), DateStr::MAX), 10);
+    assert_eq!(near(DateStr::MAX, DateStr::MIN), 0);
+}
+
+#[test]
+fn componentwise_sub_of_equal_dates() {
+    // Day and month subtraction borrow instead of returning 0: the days give 30 and borrow a
+    // month, the months give December and borrow a year, and the year saturates at 0
+    let date = DateStr::try_from_iso_str("2022-03-05").unwrap();
+    let difference = date - date;
+    assert_eq!(difference.to_string(), "0000-11-30");
+    assert_eq!(date.sub_days(&date), 0);
+    let earlier = DateStr::try_from_iso_str("2021-01-01").unwrap();
+    assert_eq!((date - earlier).to_string(), "0001-02-04");
+}
+
+#[test]
+fn checked_new_rejects_days_past_the_month() {
+    assert!(matches!(
+        DateStr::checked_new(Year::new(2023), Month(2), Day(29)),
+        Err(DateErrors::InvalidDay { day: 29 })
+    ));
+    assert!(matches!(
+        DateStr::checked_new(Year::new(2023), Month(4), Day(31)),
+        Err(DateErrors::InvalidDay { day: 31 })
+    ));
+}
+
+#[test]
+fn humanize_caps_months_below_a_year() {
+    let reference = DateStr::try_from_iso_str("2022-01-01").unwrap();
+    assert_eq!((reference + Days(359)).humanize_from(&reference), "in 11 months");
+    assert_eq!((reference + Days(360)).humanize_from(&reference), "in 11 months");
+    assert_eq!((reference + Days(364)).humanize_from(&reference), "in 11 months");
+    assert_eq!((reference + Days(365)).humanize_from(&reference), "in 1 year");
+    assert_eq!((reference - Days(360)).humanize_from(&reference), "11 months ago");
+}